@@ -0,0 +1,84 @@
+//! RPC methods for the bridge pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_bridge::{
+	runtime_api::BridgeApi as BridgeRuntimeApi, BridgeChainId, DepositNonce, PalletId,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+/// Bridge RPC methods.
+#[rpc(client, server)]
+pub trait BridgeApi<BlockHash> {
+	/// Relayer signatures over an outbound withdrawal, packed as `r ++ s ++ v` so they can be
+	/// submitted to the destination contract as is.
+	///
+	/// The bridge instance is selected by its 8-byte pallet id, e.g. `0x6c697472792f6267` for
+	/// `litry/bg`. Returns `None` until the relayer threshold is met.
+	#[method(name = "bridge_withdrawalSignatures")]
+	fn withdrawal_signatures(
+		&self,
+		bridge_id: Bytes,
+		dest_id: BridgeChainId,
+		nonce: DepositNonce,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Bytes>>;
+}
+
+/// Provides RPC methods to query the bridge pallet.
+pub struct Bridge<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Bridge<C, B> {
+	/// Creates a new instance of the Bridge RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, Block> BridgeApiServer<<Block as BlockT>::Hash> for Bridge<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BridgeRuntimeApi<Block>,
+{
+	fn withdrawal_signatures(
+		&self,
+		bridge_id: Bytes,
+		dest_id: BridgeChainId,
+		nonce: DepositNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Bytes>> {
+		let bridge_id = <[u8; 8]>::try_from(bridge_id.as_ref()).map(PalletId).map_err(|_| {
+			CallError::Custom(ErrorObject::owned(
+				2,
+				"Invalid bridge id, expected an 8-byte pallet id.",
+				None::<()>,
+			))
+		})?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.withdrawal_signatures(at, bridge_id, dest_id, nonce)
+			.map(|signatures| signatures.map(Into::into))
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to query withdrawal signatures.",
+					Some(e.to_string()),
+				))
+				.into()
+			})
+	}
+}
@@ -24,8 +24,12 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
 
+mod bridge;
 mod eth;
-pub use self::eth::{create_eth, overrides_handle, EthDeps};
+pub use self::{
+	bridge::{Bridge, BridgeApiServer},
+	eth::{create_eth, overrides_handle, EthDeps},
+};
 
 /// Full client dependencies.
 pub struct FullDeps<C, P, A: ChainApi, CT, CIDP> {
@@ -69,6 +73,7 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
+	C::Api: pallet_bridge::runtime_api::BridgeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
 	C: BlockchainEvents<Block> + AuxStore + UsageProvider<Block> + StorageProvider<Block, BE>,
 	BE: Backend<Block> + 'static,
@@ -84,7 +89,8 @@ where
 	let FullDeps { client, pool, deny_unsafe, eth } = deps;
 
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	io.merge(TransactionPayment::new(client).into_rpc())?;
+	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(Bridge::new(client).into_rpc())?;
 
 	// Ethereum compatibility RPCs
 	let io = create_eth::<_, _, _, _, _, _, _, DefaultEthConfig<C, BE>>(
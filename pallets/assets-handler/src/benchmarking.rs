@@ -0,0 +1,329 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! assets-handler benchmark file
+
+#![cfg(feature = "runtime-benchmarks")]
+#![allow(clippy::type_complexity)]
+#![allow(clippy::duplicated_attributes)]
+use super::*;
+use crate::Pallet as AssetsHandler;
+use frame_benchmarking::{
+	account, benchmarks_instance_pallet, impl_benchmark_test_suite, whitelisted_caller,
+	BenchmarkError,
+};
+use frame_support::traits::{fungibles::Create, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use pallet_assets::BenchmarkHelper;
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+const NATIVE_RESOURCE_ID: ResourceId = [1u8; 32];
+const ASSET_RESOURCE_ID: ResourceId = [2u8; 32];
+
+type BalanceOf<T, I> = <T as pallet_bridge::Config<I>>::Balance;
+type AssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
+
+/// `units` existential deposits of the native token
+fn deposits<T: Config<I>, I: 'static>(units: u32) -> BalanceOf<T, I> {
+	let ed: BalanceOf<T, I> =
+		convert_balance(<T as pallet_balances::Config>::ExistentialDeposit::get());
+	ed.max(1u32.into()).saturating_mul(units.into())
+}
+
+fn create_asset<T: Config<I>, I: 'static>(id: u32) -> Result<AssetIdOf<T>, BenchmarkError> {
+	let asset_id: AssetIdOf<T> =
+		<T as pallet_assets::Config>::BenchmarkHelper::create_asset_id_parameter(id).into();
+	<pallet_assets::Pallet<T> as Create<T::AccountId>>::create(
+		asset_id.clone(),
+		AssetsHandler::<T, I>::custody_account(),
+		true,
+		1u32.into(),
+	)?;
+	Ok(asset_id)
+}
+
+/// Maps a resource charging a fee in both directions, which is paid out to `n` fee recipients
+/// besides the treasury and the staking pot.
+fn setup_resource<T: Config<I>, I: 'static>(
+	resource_id: ResourceId,
+	asset: Option<AssetIdOf<T>>,
+	n: u32,
+) {
+	let fee = FeeModel {
+		flat: Zero::zero(),
+		proportional: Permill::from_percent(10),
+		min: Zero::zero(),
+		max: None,
+	};
+	ResourceToAssetInfo::<T, I>::insert(
+		resource_id,
+		AssetInfo { bridge_out_fee: fee.clone(), bridge_in_fee: fee, asset },
+	);
+	ResourceDecimals::<T, I>::insert(resource_id, T::LocalDecimals::get());
+
+	let share = Permill::from_parts(500_000 / n.max(1));
+	let accounts: Vec<_> = (0..n).map(|i| (account("recipient", i, SEED), share)).collect();
+	BridgeFeeDistribution::<T, I>::put(FeeDistribution {
+		treasury: Permill::from_parts(700_000 - share.deconstruct() * n),
+		burn: Permill::from_percent(10),
+		staking_pot: Permill::from_percent(20),
+		accounts,
+	});
+}
+
+fn queue_release<T: Config<I>, I: 'static>(frozen: bool) -> ReleaseId {
+	let release_id = AssetsHandler::<T, I>::next_release_id();
+	NextReleaseId::<T, I>::put(release_id.saturating_add(1));
+	PendingReleases::<T, I>::insert(
+		release_id,
+		PendingRelease {
+			resource_id: NATIVE_RESOURCE_ID,
+			asset: None,
+			who: account("who", 0, SEED),
+			amount: deposits::<T, I>(1),
+			release_at: Zero::zero(),
+			frozen,
+		},
+	);
+	release_id
+}
+
+benchmarks_instance_pallet! {
+	where_clause { where
+		T: pallet_assets::Config<Balance = BalanceOf<T, I>>
+			+ pallet_balances::Config<Balance = BalanceOf<T, I>>,
+	}
+
+	set_resource{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let info = AssetInfo {
+			bridge_out_fee: FeeModel::flat(deposits::<T, I>(1)),
+			bridge_in_fee: FeeModel::flat(deposits::<T, I>(1)),
+			asset: None,
+		};
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID, info.clone())
+	verify{
+		assert_eq!(ResourceToAssetInfo::<T, I>::get(NATIVE_RESOURCE_ID), Some(info));
+	}
+
+	remove_resource{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		setup_resource::<T, I>(NATIVE_RESOURCE_ID, None, T::MaxFeeRecipients::get());
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID)
+	verify{
+		assert!(!ResourceToAssetInfo::<T, I>::contains_key(NATIVE_RESOURCE_ID));
+	}
+
+	set_resource_state{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		setup_resource::<T, I>(NATIVE_RESOURCE_ID, None, 0);
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID, ResourceState::Paused)
+	verify{
+		assert_eq!(ResourceStates::<T, I>::get(NATIVE_RESOURCE_ID), ResourceState::Paused);
+	}
+
+	set_large_transfer_threshold{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let threshold = Some(deposits::<T, I>(1_000));
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID, threshold)
+	verify{
+		assert_eq!(LargeTransferThreshold::<T, I>::get(NATIVE_RESOURCE_ID), threshold);
+	}
+
+	cancel_release{
+		let origin = T::GuardianOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let release_id = queue_release::<T, I>(false);
+	}:_<T::RuntimeOrigin>(origin, release_id)
+	verify{
+		assert!(!PendingReleases::<T, I>::contains_key(release_id));
+	}
+
+	freeze_release{
+		let origin = T::GuardianOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let release_id = queue_release::<T, I>(false);
+	}:_<T::RuntimeOrigin>(origin, release_id)
+	verify{
+		assert!(PendingReleases::<T, I>::get(release_id).unwrap().frozen);
+	}
+
+	thaw_release{
+		let origin = T::GuardianOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let release_id = queue_release::<T, I>(true);
+	}:_<T::RuntimeOrigin>(origin, release_id)
+	verify{
+		assert!(!PendingReleases::<T, I>::get(release_id).unwrap().frozen);
+	}
+
+	// minting an asset is the heavier branch
+	release{
+		let asset = create_asset::<T, I>(0)?;
+		setup_resource::<T, I>(ASSET_RESOURCE_ID, Some(asset.clone()), T::MaxFeeRecipients::get());
+		LargeTransferThreshold::<T, I>::insert(ASSET_RESOURCE_ID, BalanceOf::<T, I>::zero());
+		let who: T::AccountId = account("who", 0, SEED);
+		let release_id = AssetsHandler::<T, I>::next_release_id();
+		AssetsHandler::<T, I>::prepare_token_bridge_in(
+			ASSET_RESOURCE_ID,
+			who.clone(),
+			deposits::<T, I>(1_000_000),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::ReleaseDelay::get()),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+	}:_(RawOrigin::Signed(caller), release_id)
+	verify{
+		assert!(!PendingReleases::<T, I>::contains_key(release_id));
+		assert!(!pallet_assets::Pallet::<T>::balance(asset, &who).is_zero());
+	}
+
+	set_custody_mode{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID, CustodyMode::LockUnlock)
+	verify{
+		assert_eq!(ResourceCustodyMode::<T, I>::get(NATIVE_RESOURCE_ID), CustodyMode::LockUnlock);
+	}
+
+	set_resource_decimals{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let decimals = Some(T::LocalDecimals::get());
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID, decimals)
+	verify{
+		assert_eq!(ResourceDecimals::<T, I>::get(NATIVE_RESOURCE_ID), decimals);
+	}
+
+	set_fee_distribution{
+		let n in 0 .. T::MaxFeeRecipients::get();
+
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let share = Permill::from_parts(1_000_000 / (n + 1));
+		let distribution = FeeDistribution {
+			treasury: Permill::from_parts(1_000_000 - share.deconstruct() * n),
+			burn: Permill::zero(),
+			staking_pot: Permill::zero(),
+			accounts: (0..n).map(|i| (account("recipient", i, SEED), share)).collect(),
+		};
+	}:_<T::RuntimeOrigin>(origin, distribution.clone())
+	verify{
+		assert_eq!(BridgeFeeDistribution::<T, I>::get(), distribution);
+	}
+
+	set_native_bridge_out_fee{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let fee = Some(deposits::<T, I>(10));
+	}:_<T::RuntimeOrigin>(origin, NATIVE_RESOURCE_ID, fee)
+	verify{
+		assert_eq!(NativeBridgeOutFee::<T, I>::get(NATIVE_RESOURCE_ID), fee);
+	}
+
+	register_bridged_asset{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let asset_id: AssetIdOf<T> =
+			<T as pallet_assets::Config>::BenchmarkHelper::create_asset_id_parameter(0).into();
+		let info = AssetInfo {
+			bridge_out_fee: Default::default(),
+			bridge_in_fee: Default::default(),
+			asset: Some(asset_id),
+		};
+		let limit = <T as pallet_assets::Config>::StringLimit::get() as usize;
+	}:_<T::RuntimeOrigin>(
+		origin,
+		ASSET_RESOURCE_ID,
+		info,
+		vec![b'A'; limit],
+		vec![b'A'; limit],
+		18,
+		true,
+		1u32.into()
+	)
+	verify{
+		assert!(ResourceToAssetInfo::<T, I>::contains_key(ASSET_RESOURCE_ID));
+	}
+
+	bridge_in_native{
+		let n in 0 .. T::MaxFeeRecipients::get();
+
+		setup_resource::<T, I>(NATIVE_RESOURCE_ID, None, n);
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount = deposits::<T, I>(1_000_000);
+	}:{
+		AssetsHandler::<T, I>::prepare_token_bridge_in(NATIVE_RESOURCE_ID, who.clone(), amount)?;
+	}
+	verify{
+		assert!(!pallet_balances::Pallet::<T>::free_balance(&who).is_zero());
+	}
+
+	bridge_in_asset{
+		let n in 0 .. T::MaxFeeRecipients::get();
+
+		let asset = create_asset::<T, I>(0)?;
+		setup_resource::<T, I>(ASSET_RESOURCE_ID, Some(asset.clone()), n);
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount = deposits::<T, I>(1_000_000);
+	}:{
+		AssetsHandler::<T, I>::prepare_token_bridge_in(ASSET_RESOURCE_ID, who.clone(), amount)?;
+	}
+	verify{
+		assert!(!pallet_assets::Pallet::<T>::balance(asset, &who).is_zero());
+	}
+
+	bridge_out_native{
+		let n in 0 .. T::MaxFeeRecipients::get();
+
+		setup_resource::<T, I>(NATIVE_RESOURCE_ID, None, n);
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount = deposits::<T, I>(1_000_000);
+		<pallet_balances::Pallet<T> as FMutate<_>>::mint_into(
+			&who,
+			amount.saturating_mul(2u32.into()),
+		)?;
+	}:{
+		AssetsHandler::<T, I>::prepare_token_bridge_out(NATIVE_RESOURCE_ID, who.clone(), amount)?;
+	}
+	verify{
+		assert!(!AssetsHandler::<T, I>::bridged_supply(NATIVE_RESOURCE_ID).bridged_out.is_zero());
+	}
+
+	bridge_out_asset{
+		let n in 0 .. T::MaxFeeRecipients::get();
+
+		let asset = create_asset::<T, I>(0)?;
+		setup_resource::<T, I>(ASSET_RESOURCE_ID, Some(asset.clone()), n);
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount = deposits::<T, I>(1_000_000);
+		<pallet_assets::Pallet<T> as FsMutate<_>>::mint_into(
+			asset,
+			&who,
+			amount.saturating_mul(2u32.into()),
+		)?;
+	}:{
+		AssetsHandler::<T, I>::prepare_token_bridge_out(ASSET_RESOURCE_ID, who.clone(), amount)?;
+	}
+	verify{
+		assert!(!AssetsHandler::<T, I>::bridged_supply(ASSET_RESOURCE_ID).bridged_out.is_zero());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -16,217 +16,1461 @@
 
 //! A pallet for handling bridge transfer lgoic.
 #![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
+pub mod weights;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		tokens::{
 			fungible::Mutate as FMutate, fungibles::Mutate as FsMutate, Fortitude, Precision,
+			Preservation,
 		},
-		StorageVersion,
+		Contains, StorageVersion,
 	},
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
-use pallet_bridge_transfer::BridgeHandler;
-use sp_runtime::{traits::CheckedSub, ArithmeticError, DispatchError, FixedPointOperand};
+use pallet_bridge_transfer::{BridgeHandler, BridgeInOutcome};
+use sp_core::H160;
+use sp_runtime::{
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Convert, Saturating,
+		Zero,
+	},
+	ArithmeticError, DispatchError, FixedPointOperand, Permill, SaturatedConversion,
+};
 use sp_std::{fmt::Debug, prelude::*};
+pub use weights::WeightInfo;
 type ResourceId = pallet_bridge::ResourceId;
+pub type ReleaseId = u64;
+
+/// Fee charged on a bridge transfer: `flat + proportional * amount`, clamped to `[min, max]`.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Debug, Decode, TypeInfo)]
+pub struct FeeModel<Balance> {
+	pub flat: Balance,
+	pub proportional: Permill,
+	pub min: Balance,
+	// None for no cap
+	pub max: Option<Balance>,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeModel<Balance> {
+	/// A fee of `fee` regardless of the amount.
+	pub fn flat(fee: Balance) -> Self {
+		Self { flat: fee, proportional: Permill::zero(), min: Zero::zero(), max: None }
+	}
+
+	pub fn is_valid(&self) -> bool {
+		self.max.map_or(true, |max| self.min <= max)
+	}
+
+	pub fn fee(&self, amount: Balance) -> Balance {
+		let fee = self.flat.saturating_add(self.proportional.mul_floor(amount)).max(self.min);
+		self.max.map_or(fee, |max| fee.min(max))
+	}
+
+	/// Returns `amount` after the fee and the fee, or `None` if it can't pay a non-zero fee.
+	pub fn deduct(&self, amount: Balance) -> Option<(Balance, Balance)> {
+		let fee = self.fee(amount);
+		if fee.is_zero() || amount > fee {
+			Some((amount - fee, fee))
+		} else {
+			None
+		}
+	}
+}
+
+/// How the tokens of a resource are accounted for when bridged.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Encode, Debug, Decode, TypeInfo, MaxEncodedLen)]
+pub enum CustodyMode {
+	/// Burned on bridge-out and minted on bridge-in
+	#[default]
+	BurnMint,
+	/// Locked in the custody account on bridge-out and unlocked from it on bridge-in, leaving
+	/// the total issuance untouched
+	LockUnlock,
+}
+
+/// Which bridge directions a resource accepts.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Encode, Debug, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ResourceState {
+	#[default]
+	Active,
+	InboundOnly,
+	OutboundOnly,
+	/// Both directions are stopped
+	Paused,
+	/// Retired, only drained inbound
+	Deprecated,
+}
+
+impl ResourceState {
+	pub fn allows_inbound(&self) -> bool {
+		matches!(self, Self::Active | Self::InboundOnly | Self::Deprecated)
+	}
+
+	pub fn allows_outbound(&self) -> bool {
+		matches!(self, Self::Active | Self::OutboundOnly)
+	}
+}
+
+/// Currency a bridge-out fee is charged in.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Debug, Decode, TypeInfo)]
+pub enum FeeCurrency {
+	/// Deducted from the bridged amount
+	Bridged,
+	/// Charged in native token on top of the bridged amount
+	Native,
+}
+
+/// Outcome of a bridge-out, as quoted by the runtime API.
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub struct FeeQuote<Balance> {
+	/// Received on the destination chain, in its units
+	pub amount: Balance,
+	pub fee: Balance,
+	pub currency: FeeCurrency,
+}
+
+/// Shares of the bridge fees, summing up to 100%. The treasury also receives the rounding dust.
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub struct FeeDistribution<AccountId> {
+	pub treasury: Permill,
+	pub burn: Permill,
+	pub staking_pot: Permill,
+	pub accounts: Vec<(AccountId, Permill)>,
+}
+
+impl<AccountId> Default for FeeDistribution<AccountId> {
+	fn default() -> Self {
+		Self {
+			treasury: Permill::one(),
+			burn: Permill::zero(),
+			staking_pot: Permill::zero(),
+			accounts: Vec::new(),
+		}
+	}
+}
+
+impl<AccountId> FeeDistribution<AccountId> {
+	pub fn is_valid(&self) -> bool {
+		let total = [self.treasury, self.burn, self.staking_pot]
+			.iter()
+			.chain(self.accounts.iter().map(|(_, share)| share))
+			.map(|share| share.deconstruct() as u64)
+			.sum::<u64>();
+		total == Permill::one().deconstruct() as u64
+	}
+}
+
+/// Destination of a part of the bridge fees.
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub enum FeeDestination<AccountId> {
+	Treasury,
+	Burn,
+	StakingPot,
+	Account(AccountId),
+}
 
 #[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
 pub struct AssetInfo<AssetId, Balance> {
-	fee: Balance,
+	bridge_out_fee: FeeModel<Balance>,
+	bridge_in_fee: FeeModel<Balance>,
 	// None for native token
 	asset: Option<AssetId>,
 }
 
+/// Cumulative bridged amounts of a resource, in local units.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Debug, Decode, TypeInfo, MaxEncodedLen)]
+pub struct BridgedSupply<Balance> {
+	/// Minted or unlocked by bridge-ins, fees included unless burned
+	pub bridged_in: Balance,
+	/// Burned or locked by bridge-outs, fees excluded unless burned
+	pub bridged_out: Balance,
+	/// Fees charged in both directions
+	pub fees: Balance,
+}
+
+/// A large bridge-in waiting for its time lock to expire.
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub struct PendingRelease<AssetId, AccountId, Balance, BlockNumber> {
+	pub resource_id: ResourceId,
+	// None for native token
+	pub asset: Option<AssetId>,
+	pub who: AccountId,
+	pub amount: Balance,
+	/// Block from which the release can be minted
+	pub release_at: BlockNumber,
+	/// Frozen releases are kept until thawed or cancelled by the guardian
+	pub frozen: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
-	type BalanceOf<T> = <T as pallet_bridge::Config>::Balance;
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	type BalanceOf<T, I> = <T as pallet_bridge::Config<I>>::Balance;
 	type AssetId<T> = <T as pallet_assets::Config>::AssetId;
+	pub type PendingReleaseOf<T, I> = PendingRelease<
+		AssetId<T>,
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		BlockNumberFor<T>,
+	>;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
-	pub trait Config:
+	pub trait Config<I: 'static = ()>:
 		frame_system::Config
 		+ pallet_balances::Config
 		+ pallet_assets::Config
-		+ pallet_bridge::Config
+		+ pallet_bridge::Config<I>
 	{
 		/// Overarching event type
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Treasury account to receive assets fee
 		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Staking reward pot receiving its share of the fees
+		type StakingRewardPot: Get<Self::AccountId>;
+
+		/// Maximum number of accounts in the fee distribution
+		#[pallet::constant]
+		type MaxFeeRecipients: Get<u32>;
+
+		/// ERC-20 precompile address of an asset
+		type AssetAddress: Convert<AssetId<Self>, H160>;
+
+		/// Origin that can cancel or freeze pending releases
+		type GuardianOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Time lock of bridge-ins above the large transfer threshold
+		#[pallet::constant]
+		type ReleaseDelay: Get<BlockNumberFor<Self>>;
+
+		/// Derives the custody account of resources in lock/unlock mode
+		#[pallet::constant]
+		type CustodyPalletId: Get<PalletId>;
+
+		/// Decimals of the local representation of bridged tokens
+		#[pallet::constant]
+		type LocalDecimals: Get<u8>;
+
+		/// Assets only minted by the bridge, whose total supply must match the outstanding
+		/// bridged supply
+		type BridgeOnlyAssets: Contains<AssetId<Self>>;
+
+		/// Weight information for the extrinsics and bridge transfers of this pallet
+		type WeightInfo: WeightInfo;
 	}
 
 	// Resource Id of pallet assets token
 	#[pallet::storage]
 	#[pallet::getter(fn resource_to_asset_info)]
-	pub type ResourceToAssetInfo<T: Config> =
-		StorageMap<_, Twox64Concat, ResourceId, AssetInfo<AssetId<T>, BalanceOf<T>>, OptionQuery>;
+	pub type ResourceToAssetInfo<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		AssetInfo<AssetId<T>, BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// Bridge-ins above this amount are time-locked, per resource
+	#[pallet::storage]
+	#[pallet::getter(fn large_transfer_threshold)]
+	pub type LargeTransferThreshold<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, BalanceOf<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn resource_state)]
+	pub type ResourceStates<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, ResourceState, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn custody_mode)]
+	pub type ResourceCustodyMode<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, CustodyMode, ValueQuery>;
+
+	/// Decimals of a resource on the source chain. Bridged amounts are rescaled between them
+	/// and `LocalDecimals`, None means no rescaling.
+	#[pallet::storage]
+	#[pallet::getter(fn resource_decimals)]
+	pub type ResourceDecimals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, u8, OptionQuery>;
+
+	/// Flat native fee charged on top of bridge-outs of a resource, instead of deducting its
+	/// bridge-out fee from the bridged amount
+	#[pallet::storage]
+	#[pallet::getter(fn native_bridge_out_fee)]
+	pub type NativeBridgeOutFee<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, BalanceOf<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn fee_distribution)]
+	pub type BridgeFeeDistribution<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, FeeDistribution<T::AccountId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bridged_supply)]
+	pub type BridgedSupplies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, BridgedSupply<BalanceOf<T, I>>, ValueQuery>;
+
+	/// Tokens of a resource held in the custody account
+	#[pallet::storage]
+	#[pallet::getter(fn custody_balance)]
+	pub type CustodyBalance<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ResourceId, BalanceOf<T, I>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_release_id)]
+	pub type NextReleaseId<T: Config<I>, I: 'static = ()> = StorageValue<_, ReleaseId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_release)]
+	pub type PendingReleases<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ReleaseId, PendingReleaseOf<T, I>, OptionQuery>;
+
+	/// Raw key of the last pending release scanned by `on_idle`, the next scan resumes after it
+	/// so that releases which aren't due can't starve the others
+	#[pallet::storage]
+	pub type ReleaseScanCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<u8>, OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		// asset id = None means native token
 		ResourceUpdated {
 			resource_id: ResourceId,
-			asset: AssetInfo<AssetId<T>, BalanceOf<T>>,
+			asset: AssetInfo<AssetId<T>, BalanceOf<T, I>>,
 		},
 		ResourceRemoved {
 			resource_id: ResourceId,
 		},
+		ResourceStateChanged {
+			resource_id: ResourceId,
+			state: ResourceState,
+		},
 		/// A certain amount of asset tokens was minted
 		TokenBridgeIn {
 			asset_id: Option<AssetId<T>>,
 			to: T::AccountId,
-			amount: BalanceOf<T>,
+			// After Fee
+			amount: BalanceOf<T, I>,
+			fee: BalanceOf<T, I>,
 		},
 		TokenBridgeOut {
 			asset_id: Option<AssetId<T>>,
 			to: T::AccountId,
 			// Before Fee
-			amount: BalanceOf<T>,
-			fee: BalanceOf<T>,
+			amount: BalanceOf<T, I>,
+			fee: BalanceOf<T, I>,
+		},
+		/// Large transfer threshold of a resource changed, None means no time lock
+		LargeTransferThresholdSet {
+			resource_id: ResourceId,
+			threshold: Option<BalanceOf<T, I>>,
+		},
+		/// A large or quarantined bridge-in was time-locked
+		ReleaseQueued {
+			release_id: ReleaseId,
+			resource_id: ResourceId,
+			to: T::AccountId,
+			amount: BalanceOf<T, I>,
+			release_at: BlockNumberFor<T>,
+		},
+		ReleaseCancelled {
+			release_id: ReleaseId,
+		},
+		ReleaseFrozen {
+			release_id: ReleaseId,
+		},
+		ReleaseThawed {
+			release_id: ReleaseId,
+		},
+		/// A time-locked bridge-in was minted
+		Released {
+			release_id: ReleaseId,
+		},
+		CustodyModeSet {
+			resource_id: ResourceId,
+			mode: CustodyMode,
+		},
+		ResourceDecimalsSet {
+			resource_id: ResourceId,
+			decimals: Option<u8>,
+		},
+		FeeDistributionSet {
+			distribution: FeeDistribution<T::AccountId>,
+		},
+		/// Native bridge-out fee of a resource changed, None deducts the fee from the bridged
+		/// amount
+		NativeBridgeOutFeeSet {
+			resource_id: ResourceId,
+			fee: Option<BalanceOf<T, I>>,
+		},
+		/// A bridged asset was created and mapped to a resource
+		BridgedAssetRegistered {
+			resource_id: ResourceId,
+			asset_id: AssetId<T>,
+			// ERC-20 precompile address
+			address: H160,
+		},
+		/// The bridge-out fee was charged in native token
+		NativeFeeCharged {
+			resource_id: ResourceId,
+			who: T::AccountId,
+			fee: BalanceOf<T, I>,
+		},
+		/// A part of a bridge fee was paid out
+		FeeDistributed {
+			resource_id: ResourceId,
+			destination: FeeDestination<T::AccountId>,
+			amount: BalanceOf<T, I>,
+		},
+		/// Bridged-out tokens were moved into custody
+		Locked {
+			resource_id: ResourceId,
+			from: T::AccountId,
+			amount: BalanceOf<T, I>,
+			// Custody balance of the resource after the lock
+			custody: BalanceOf<T, I>,
+		},
+		/// Bridged-in tokens were released from custody
+		Unlocked {
+			resource_id: ResourceId,
+			to: T::AccountId,
+			amount: BalanceOf<T, I>,
+			// Custody balance of the resource after the unlock
+			custody: BalanceOf<T, I>,
 		},
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		InvalidResourceId,
 		CannotPayAsFee,
+		/// The resource doesn't accept bridge-ins in its current state
+		InboundDisabled,
+		/// The resource doesn't accept bridge-outs in its current state
+		OutboundDisabled,
+		/// The minimum fee exceeds the maximum
+		InvalidFeeModel,
+		/// Custody holds less than the bridge-in of a lock/unlock resource
+		InsufficientCustody,
+		/// The custody mode can't be changed while tokens are in custody
+		CustodyNotEmpty,
+		/// Source chain decimals are too far from the local ones to rescale amounts
+		InvalidDecimals,
+		/// Bridged assets must be `pallet_assets` tokens
+		NotAnAsset,
+		/// Fee distribution shares don't sum up to 100%
+		InvalidFeeDistribution,
+		TooManyFeeRecipients,
+		ReleaseNotFound,
+		/// The time lock of the release hasn't expired yet
+		ReleaseNotDue,
+		ReleaseIsFrozen,
+		/// No asset info is registered for the resource
+		ResourceNotFound,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Mints due releases with the remaining weight of the block. Each call scans on from
+		/// where the previous one stopped, starting over once all releases were scanned.
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let scan_weight = T::DbWeight::get().reads(1);
+			let release_weight = <T as Config<I>>::WeightInfo::release();
+			// 1 read, 1 write: `release_scan_cursor`
+			let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+			if used_weight.any_gt(remaining_weight) {
+				return Weight::zero()
+			}
+
+			let mut releases = match ReleaseScanCursor::<T, I>::take() {
+				Some(cursor) => PendingReleases::<T, I>::iter_from(cursor),
+				None => PendingReleases::<T, I>::iter(),
+			};
+			let mut due = Vec::new();
+			loop {
+				if used_weight
+					.saturating_add(scan_weight)
+					.saturating_add(release_weight)
+					.any_gt(remaining_weight)
+				{
+					ReleaseScanCursor::<T, I>::put(releases.last_raw_key().to_vec());
+					break;
+				}
+				let Some((release_id, pending)) = releases.next() else { break };
+				used_weight.saturating_accrue(scan_weight);
+				if !pending.frozen && pending.release_at <= now {
+					used_weight.saturating_accrue(release_weight);
+					due.push((release_id, pending));
+				}
+			}
+
+			for (release_id, pending) in due {
+				// releases of removed resources are dropped, nothing is minted
+				if !ResourceToAssetInfo::<T, I>::contains_key(pending.resource_id) {
+					PendingReleases::<T, I>::remove(release_id);
+					Self::deposit_event(Event::ReleaseCancelled { release_id });
+					continue
+				}
+				// failed releases stay pending, to be retried or cancelled
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::do_release(release_id, pending)
+				});
+			}
+			used_weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Stores an asset id on chain under an associated resource ID.
 		#[pallet::call_index(0)]
-		#[pallet::weight({1000})]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_resource())]
 		pub fn set_resource(
 			origin: OriginFor<T>,
 			resource_id: ResourceId,
-			asset: AssetInfo<AssetId<T>, BalanceOf<T>>,
+			asset: AssetInfo<AssetId<T>, BalanceOf<T, I>>,
 		) -> DispatchResult {
-			<T as pallet_bridge::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
-			ResourceToAssetInfo::<T>::insert(resource_id, asset.clone());
-			Self::deposit_event(Event::ResourceUpdated { resource_id, asset });
-			Ok(())
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			Self::do_set_resource(resource_id, asset)
 		}
 
-		/// Removes a resource ID from the resource mapping.
+		/// Removes a resource ID from the resource mapping, together with all its settings
+		/// and its bridged supply. Not allowed while tokens of the resource are in custody.
+		/// Its pending releases are never minted, `on_idle` drops them once due.
 		///
 		/// After this call, bridge transfers with the associated resource ID will
 		/// be rejected.
 		#[pallet::call_index(1)]
-		#[pallet::weight({1000})]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_resource())]
 		pub fn remove_resource(origin: OriginFor<T>, resource_id: ResourceId) -> DispatchResult {
-			<T as pallet_bridge::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
-			ResourceToAssetInfo::<T>::remove(resource_id);
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(Self::custody_balance(resource_id).is_zero(), Error::<T, I>::CustodyNotEmpty);
+			ResourceToAssetInfo::<T, I>::remove(resource_id);
+			LargeTransferThreshold::<T, I>::remove(resource_id);
+			ResourceStates::<T, I>::remove(resource_id);
+			ResourceCustodyMode::<T, I>::remove(resource_id);
+			ResourceDecimals::<T, I>::remove(resource_id);
+			NativeBridgeOutFee::<T, I>::remove(resource_id);
+			BridgedSupplies::<T, I>::remove(resource_id);
 			Self::deposit_event(Event::ResourceRemoved { resource_id });
 			Ok(())
 		}
+
+		/// Sets which bridge directions a resource accepts. Unlike `remove_resource`, in-flight
+		/// bridge-ins can still be executed once inbound is allowed again.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_resource_state())]
+		pub fn set_resource_state(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			state: ResourceState,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(
+				ResourceToAssetInfo::<T, I>::contains_key(resource_id),
+				Error::<T, I>::ResourceNotFound
+			);
+			ResourceStates::<T, I>::insert(resource_id, state);
+			Self::deposit_event(Event::ResourceStateChanged { resource_id, state });
+			Ok(())
+		}
+
+		/// Sets the amount above which bridge-ins of a resource are time-locked.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_large_transfer_threshold())]
+		pub fn set_large_transfer_threshold(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			threshold: Option<BalanceOf<T, I>>,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			LargeTransferThreshold::<T, I>::set(resource_id, threshold);
+			Self::deposit_event(Event::LargeTransferThresholdSet { resource_id, threshold });
+			Ok(())
+		}
+
+		/// Cancels a pending release, nothing is minted.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::cancel_release())]
+		pub fn cancel_release(origin: OriginFor<T>, release_id: ReleaseId) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			PendingReleases::<T, I>::take(release_id).ok_or(Error::<T, I>::ReleaseNotFound)?;
+			Self::deposit_event(Event::ReleaseCancelled { release_id });
+			Ok(())
+		}
+
+		/// Freezes a pending release until it is thawed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::freeze_release())]
+		pub fn freeze_release(origin: OriginFor<T>, release_id: ReleaseId) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			Self::set_frozen(release_id, true)?;
+			Self::deposit_event(Event::ReleaseFrozen { release_id });
+			Ok(())
+		}
+
+		/// Thaws a frozen release, it can be minted once its time lock expired.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::thaw_release())]
+		pub fn thaw_release(origin: OriginFor<T>, release_id: ReleaseId) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			Self::set_frozen(release_id, false)?;
+			Self::deposit_event(Event::ReleaseThawed { release_id });
+			Ok(())
+		}
+
+		/// Mints a release whose time lock expired, callable by anyone.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::release())]
+		pub fn release(origin: OriginFor<T>, release_id: ReleaseId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let pending =
+				Self::pending_release(release_id).ok_or(Error::<T, I>::ReleaseNotFound)?;
+			ensure!(!pending.frozen, Error::<T, I>::ReleaseIsFrozen);
+			ensure!(
+				pending.release_at <= frame_system::Pallet::<T>::block_number(),
+				Error::<T, I>::ReleaseNotDue
+			);
+			Self::do_release(release_id, pending)
+		}
+
+		/// Sets whether a resource is burned/minted or locked/unlocked in custody. Can't leave
+		/// lock/unlock mode while tokens are in custody.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_custody_mode())]
+		pub fn set_custody_mode(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			mode: CustodyMode,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(
+				mode == CustodyMode::LockUnlock || Self::custody_balance(resource_id).is_zero(),
+				Error::<T, I>::CustodyNotEmpty
+			);
+			ResourceCustodyMode::<T, I>::insert(resource_id, mode);
+			Self::deposit_event(Event::CustodyModeSet { resource_id, mode });
+			Ok(())
+		}
+
+		/// Sets the decimals of a resource on the source chain, `None` disables rescaling.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_resource_decimals())]
+		pub fn set_resource_decimals(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			Self::do_set_resource_decimals(resource_id, decimals)
+		}
+
+		/// Sets how the bridge fees of all resources are shared out.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::set_fee_distribution(distribution.accounts.len() as u32)
+		)]
+		pub fn set_fee_distribution(
+			origin: OriginFor<T>,
+			distribution: FeeDistribution<T::AccountId>,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(
+				distribution.accounts.len() as u32 <= T::MaxFeeRecipients::get(),
+				Error::<T, I>::TooManyFeeRecipients
+			);
+			ensure!(distribution.is_valid(), Error::<T, I>::InvalidFeeDistribution);
+			BridgeFeeDistribution::<T, I>::put(distribution.clone());
+			Self::deposit_event(Event::FeeDistributionSet { distribution });
+			Ok(())
+		}
+
+		/// Charges bridge-outs of a resource a flat native fee on top of the bridged amount,
+		/// which is then bridged in full. `None` deducts the bridge-out fee again.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_native_bridge_out_fee())]
+		pub fn set_native_bridge_out_fee(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			fee: Option<BalanceOf<T, I>>,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			NativeBridgeOutFee::<T, I>::set(resource_id, fee);
+			Self::deposit_event(Event::NativeBridgeOutFeeSet { resource_id, fee });
+			Ok(())
+		}
+
+		/// Creates the `pallet_assets` token of `asset`, owned by this pallet, with its metadata
+		/// and stores it under `resource_id`. `decimals` are the token's decimals on the source
+		/// chain, the local token has `LocalDecimals` and bridged amounts are rescaled.
+		///
+		/// The origin must also be accepted as the force origin of `pallet_assets`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::register_bridged_asset())]
+		#[allow(clippy::too_many_arguments)]
+		pub fn register_bridged_asset(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			asset: AssetInfo<AssetId<T>, BalanceOf<T, I>>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			is_sufficient: bool,
+			min_balance: BalanceOf<T, I>,
+		) -> DispatchResult {
+			use frame_support::traits::fungibles::Create;
+
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin.clone())?;
+			let asset_id = asset.asset.clone().ok_or(Error::<T, I>::NotAnAsset)?;
+			let local_decimals = T::LocalDecimals::get();
+			<pallet_assets::Pallet<T> as Create<_>>::create(
+				asset_id.clone(),
+				Self::custody_account(),
+				is_sufficient,
+				convert_balance(min_balance),
+			)?;
+			// the pallet account has no funds for the metadata deposit
+			pallet_assets::Pallet::<T>::force_set_metadata(
+				origin,
+				asset_id.clone().into(),
+				name,
+				symbol,
+				local_decimals,
+				false,
+			)?;
+			Self::do_set_resource_decimals(
+				resource_id,
+				(decimals != local_decimals).then_some(decimals),
+			)?;
+			Self::do_set_resource(resource_id, asset)?;
+			Self::deposit_event(Event::BridgedAssetRegistered {
+				resource_id,
+				asset_id: asset_id.clone(),
+				address: T::AssetAddress::convert(asset_id),
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		fn do_set_resource(
+			resource_id: ResourceId,
+			asset: AssetInfo<AssetId<T>, BalanceOf<T, I>>,
+		) -> DispatchResult {
+			ensure!(
+				asset.bridge_out_fee.is_valid() && asset.bridge_in_fee.is_valid(),
+				Error::<T, I>::InvalidFeeModel
+			);
+			ResourceToAssetInfo::<T, I>::insert(resource_id, asset.clone());
+			Self::deposit_event(Event::ResourceUpdated { resource_id, asset });
+			Ok(())
+		}
+
+		fn do_set_resource_decimals(
+			resource_id: ResourceId,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			if let Some(decimals) = decimals {
+				ensure!(
+					T::LocalDecimals::get().abs_diff(decimals) <= MAX_DECIMALS_DIFF,
+					Error::<T, I>::InvalidDecimals
+				);
+			}
+			ResourceDecimals::<T, I>::set(resource_id, decimals);
+			Self::deposit_event(Event::ResourceDecimalsSet { resource_id, decimals });
+			Ok(())
+		}
+
+		/// All pending releases, for the runtime API.
+		pub fn pending_releases() -> Vec<(ReleaseId, PendingReleaseOf<T, I>)> {
+			PendingReleases::<T, I>::iter().collect()
+		}
+
+		/// Amount received on the destination chain for bridging out `amount` and the fee
+		/// charged, for the runtime API. None if the resource is unknown or `amount` can't pay
+		/// the fee.
+		pub fn quote_bridge_out(
+			resource_id: ResourceId,
+			amount: BalanceOf<T, I>,
+		) -> Option<FeeQuote<BalanceOf<T, I>>> {
+			let info = Self::resource_to_asset_info(resource_id)?;
+			if !Self::resource_state(resource_id).allows_outbound() {
+				return None
+			}
+			let (net, fee, currency) = match Self::native_bridge_out_fee(resource_id) {
+				Some(fee) => (amount, fee, FeeCurrency::Native),
+				None => {
+					let (net, fee) = info.bridge_out_fee.deduct(amount)?;
+					(net, fee, FeeCurrency::Bridged)
+				},
+			};
+			let (amount, _) = Self::to_remote(resource_id, net).ok()?;
+			Some(FeeQuote { amount, fee, currency })
+		}
+
+		/// Amount received here for bridging in `amount`, for the runtime API. None if the
+		/// resource is unknown or `amount` can't pay the fee.
+		pub fn quote_bridge_in(
+			resource_id: ResourceId,
+			amount: BalanceOf<T, I>,
+		) -> Option<BalanceOf<T, I>> {
+			let info = Self::resource_to_asset_info(resource_id)?;
+			if !Self::resource_state(resource_id).allows_inbound() {
+				return None
+			}
+			let amount = Self::to_local(resource_id, amount).ok()?;
+			info.bridge_in_fee.deduct(amount).map(|(net, _)| net)
+		}
+
+		/// Converts an amount in source chain units into local units. Dust below the local
+		/// precision stays on the source chain.
+		pub fn to_local(
+			resource_id: ResourceId,
+			amount: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			let Some(remote) = Self::resource_decimals(resource_id) else { return Ok(amount) };
+			let local = T::LocalDecimals::get();
+			let factor = decimals_factor(local.abs_diff(remote));
+			let amount = amount.saturated_into::<u128>();
+			let scaled = if local >= remote {
+				amount.checked_mul(factor).ok_or(ArithmeticError::Overflow)?
+			} else {
+				amount / factor
+			};
+			Ok(scaled.try_into().map_err(|_| ArithmeticError::Overflow)?)
+		}
+
+		/// Converts a local amount into source chain units, also returning the local dust that
+		/// can't be represented there.
+		pub fn to_remote(
+			resource_id: ResourceId,
+			amount: BalanceOf<T, I>,
+		) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+			let Some(remote) = Self::resource_decimals(resource_id) else {
+				return Ok((amount, Zero::zero()))
+			};
+			let local = T::LocalDecimals::get();
+			let factor = decimals_factor(local.abs_diff(remote));
+			let amount = amount.saturated_into::<u128>();
+			let (scaled, dust) = if local >= remote {
+				(amount / factor, amount % factor)
+			} else {
+				(amount.checked_mul(factor).ok_or(ArithmeticError::Overflow)?, 0)
+			};
+			Ok((scaled.try_into().map_err(|_| ArithmeticError::Overflow)?, dust.saturated_into()))
+		}
+
+		/// Tokens of a resource that are backed by the counterpart chain in burn/mint mode, or
+		/// locked here to back the counterpart chain's supply in lock/unlock mode.
+		pub fn outstanding_supply(resource_id: ResourceId) -> BalanceOf<T, I> {
+			match Self::custody_mode(resource_id) {
+				CustodyMode::BurnMint => {
+					let supply = Self::bridged_supply(resource_id);
+					supply.bridged_in.saturating_sub(supply.bridged_out)
+				},
+				CustodyMode::LockUnlock => Self::custody_balance(resource_id),
+			}
+		}
+
+		/// Outstanding supply of all bridged resources, for the runtime API.
+		pub fn outstanding_supplies() -> Vec<(ResourceId, BalanceOf<T, I>)> {
+			BridgedSupplies::<T, I>::iter_keys()
+				.map(|resource_id| (resource_id, Self::outstanding_supply(resource_id)))
+				.collect()
+		}
+
+		/// Checks that the total supply of bridge-only assets equals their outstanding supply
+		/// over all burn/mint resources.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			use frame_support::traits::fungibles::Inspect;
+
+			let mut outstanding: Vec<(AssetId<T>, BalanceOf<T, I>)> = Vec::new();
+			for (resource_id, info) in ResourceToAssetInfo::<T, I>::iter() {
+				let Some(asset) = info.asset else { continue };
+				if !T::BridgeOnlyAssets::contains(&asset) ||
+					Self::custody_mode(resource_id) != CustodyMode::BurnMint
+				{
+					continue
+				}
+				let supply = Self::outstanding_supply(resource_id);
+				match outstanding.iter_mut().find(|(a, _)| *a == asset) {
+					Some((_, total)) => total.saturating_accrue(supply),
+					None => outstanding.push((asset, supply)),
+				}
+			}
+			for (asset, supply) in outstanding {
+				let total_issuance: BalanceOf<T, I> =
+					convert_balance(pallet_assets::Pallet::<T>::total_issuance(asset));
+				ensure!(
+					total_issuance == supply,
+					"total supply of a bridge-only asset differs from its bridged supply"
+				);
+			}
+			Ok(())
+		}
+
+		/// Account holding the tokens of resources in lock/unlock mode, also owning the
+		/// registered bridged assets.
+		pub fn custody_account() -> T::AccountId {
+			T::CustodyPalletId::get().into_account_truncating()
+		}
+
+		fn set_frozen(release_id: ReleaseId, frozen: bool) -> DispatchResult {
+			PendingReleases::<T, I>::try_mutate(release_id, |pending| {
+				let pending = pending.as_mut().ok_or(Error::<T, I>::ReleaseNotFound)?;
+				pending.frozen = frozen;
+				Ok(())
+			})
+		}
+
+		fn do_release(release_id: ReleaseId, pending: PendingReleaseOf<T, I>) -> DispatchResult {
+			ensure!(
+				ResourceToAssetInfo::<T, I>::contains_key(pending.resource_id),
+				Error::<T, I>::ResourceNotFound
+			);
+			ensure!(
+				Self::resource_state(pending.resource_id).allows_inbound(),
+				Error::<T, I>::InboundDisabled
+			);
+			Self::mint(
+				pending.resource_id,
+				pending.asset,
+				&pending.who,
+				pending.amount,
+				Zero::zero(),
+			)?;
+			PendingReleases::<T, I>::remove(release_id);
+			Self::deposit_event(Event::Released { release_id });
+			Ok(())
+		}
+
+		/// Checks that `resource_id` accepts bridge-ins and deducts its bridge-in fee from
+		/// `amount`, converted to local units. Returns the asset, the net amount and the fee.
+		fn deduct_bridge_in_fee(
+			resource_id: ResourceId,
+			amount: BalanceOf<T, I>,
+		) -> Result<(Option<AssetId<T>>, BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+			let AssetInfo { bridge_in_fee, asset, .. } = Self::resource_to_asset_info(resource_id)
+				.ok_or(Error::<T, I>::InvalidResourceId)?;
+			ensure!(
+				Self::resource_state(resource_id).allows_inbound(),
+				Error::<T, I>::InboundDisabled
+			);
+			let amount = Self::to_local(resource_id, amount)?;
+			let (amount, fee) =
+				bridge_in_fee.deduct(amount).ok_or(Error::<T, I>::CannotPayAsFee)?;
+			BridgedSupplies::<T, I>::mutate(resource_id, |supply| {
+				supply.fees.saturating_accrue(fee)
+			});
+			Ok((asset, amount, fee))
+		}
+
+		/// Time-locks `amount` if it exceeds the resource's large transfer threshold, or
+		/// frozen until a guardian thaws it if `frozen`. Returns whether the bridge-in was
+		/// queued.
+		fn try_queue_release(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+			frozen: bool,
+		) -> bool {
+			match Self::large_transfer_threshold(resource_id) {
+				_ if frozen => {},
+				Some(threshold) if amount > threshold => {},
+				_ => return false,
+			}
+
+			let release_id = Self::next_release_id();
+			NextReleaseId::<T, I>::put(release_id.saturating_add(1));
+			let release_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ReleaseDelay::get());
+			PendingReleases::<T, I>::insert(
+				release_id,
+				PendingRelease { resource_id, asset, who: who.clone(), amount, release_at, frozen },
+			);
+			Self::deposit_event(Event::ReleaseQueued {
+				release_id,
+				resource_id,
+				to: who.clone(),
+				amount,
+				release_at,
+			});
+			if frozen {
+				Self::deposit_event(Event::ReleaseFrozen { release_id });
+			}
+			true
+		}
+
+		/// Mints `amount` to `who` and distributes `fee`, or unlocks them from custody.
+		fn mint(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+			fee: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			Self::deposit_event(Event::TokenBridgeIn {
+				asset_id: asset.clone(),
+				to: who.clone(),
+				amount,
+				fee,
+			});
+			let minted = Self::mint_into(resource_id, asset.clone(), who, amount)?;
+			Self::distribute_fee(resource_id, asset, FeePayer::BridgeIn, fee)?;
+			Ok(minted)
+		}
+
+		fn mint_into(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			BridgedSupplies::<T, I>::mutate(resource_id, |supply| {
+				supply.bridged_in.saturating_accrue(amount)
+			});
+			if Self::custody_mode(resource_id) == CustodyMode::LockUnlock {
+				return Self::unlock(resource_id, asset, who, amount)
+			}
+			Self::do_mint(asset, who, amount)
+		}
+
+		fn do_mint(
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			let minted = match asset {
+				// Native token
+				None => convert_balance(pallet_balances::Pallet::<T>::mint_into(
+					who,
+					convert_balance(amount),
+				)?),
+				// pallet assets
+				Some(asset) => convert_balance(pallet_assets::Pallet::<T>::mint_into(
+					asset,
+					who,
+					convert_balance(amount),
+				)?),
+			};
+			Ok(minted)
+		}
+
+		fn unlock(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			to: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			let custody = Self::custody_balance(resource_id)
+				.checked_sub(&amount)
+				.ok_or(Error::<T, I>::InsufficientCustody)?;
+			Self::transfer(asset, &Self::custody_account(), to, amount)?;
+			CustodyBalance::<T, I>::insert(resource_id, custody);
+			Self::deposit_event(Event::Unlocked { resource_id, to: to.clone(), amount, custody });
+			Ok(amount)
+		}
+
+		/// Moves `amount` of `who` into custody after distributing `fee`. Returns the locked
+		/// amount and the burned part of the fee.
+		fn lock(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+			fee: BalanceOf<T, I>,
+		) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+			let amount = amount.checked_sub(&fee).ok_or(Error::<T, I>::CannotPayAsFee)?;
+			let burned =
+				Self::distribute_fee(resource_id, asset.clone(), FeePayer::Account(who), fee)?;
+			Self::transfer(asset, who, &Self::custody_account(), amount)?;
+			let custody = Self::custody_balance(resource_id)
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			CustodyBalance::<T, I>::insert(resource_id, custody);
+			Self::deposit_event(Event::Locked { resource_id, from: who.clone(), amount, custody });
+			Ok((amount, burned))
+		}
+
+		/// Burns `amount` of `who` and distributes `fee` out of it. Returns the amount after
+		/// the fee and the burned part of the fee.
+		fn burn(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+			fee: BalanceOf<T, I>,
+		) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+			let burn_amount = Self::burn_from(asset.clone(), who, amount)?;
+			let burned = Self::distribute_fee(resource_id, asset, FeePayer::Burned, fee)?;
+			Ok((burn_amount.checked_sub(&fee).ok_or(ArithmeticError::Overflow)?, burned))
+		}
+
+		fn burn_from(
+			asset: Option<AssetId<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			let burn_amount = match asset {
+				// Native token
+				None => convert_balance(pallet_balances::Pallet::<T>::burn_from(
+					who,
+					convert_balance(amount),
+					Precision::Exact,
+					Fortitude::Polite,
+				)?),
+				// pallet assets
+				// Since we use Exact approach
+				// Burn amount will always be amount exactly
+				Some(asset) => convert_balance(pallet_assets::Pallet::<T>::burn_from(
+					asset,
+					who,
+					convert_balance(amount),
+					Precision::Exact,
+					Fortitude::Polite,
+				)?),
+			};
+			Ok(burn_amount)
+		}
+
+		/// Pays `fee` out to the destinations of the fee distribution, returns its burned
+		/// part. Shares a destination can't receive, e.g. below the minimum balance, go to the
+		/// treasury, and are burned if the treasury can't receive them either.
+		fn distribute_fee(
+			resource_id: ResourceId,
+			asset: Option<AssetId<T>>,
+			payer: FeePayer<T::AccountId>,
+			fee: BalanceOf<T, I>,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			if fee.is_zero() {
+				return Ok(fee)
+			}
+			let distribution = Self::fee_distribution();
+			let mut burn = distribution.burn.mul_floor(fee);
+			let mut payouts = vec![(
+				FeeDestination::StakingPot,
+				T::StakingRewardPot::get(),
+				distribution.staking_pot.mul_floor(fee),
+			)];
+			for (account, share) in distribution.accounts {
+				payouts.push((
+					FeeDestination::Account(account.clone()),
+					account,
+					share.mul_floor(fee),
+				));
+			}
+			for (_, account, amount) in payouts.iter_mut() {
+				if !Self::can_receive(asset.clone(), &payer, account, *amount) {
+					*amount = Zero::zero();
+				}
+			}
+			// the treasury also gets the rounding dust
+			let paid =
+				payouts.iter().fold(burn, |paid, (_, _, amount)| paid.saturating_add(*amount));
+			let treasury = T::TreasuryAccount::get();
+			let mut treasury_amount = fee.saturating_sub(paid);
+			if !Self::can_receive(asset.clone(), &payer, &treasury, treasury_amount) {
+				burn.saturating_accrue(treasury_amount);
+				treasury_amount = Zero::zero();
+			}
+			payouts.insert(0, (FeeDestination::Treasury, treasury, treasury_amount));
+
+			for (destination, account, amount) in payouts {
+				if amount.is_zero() {
+					continue
+				}
+				match payer {
+					FeePayer::BridgeIn => {
+						Self::mint_into(resource_id, asset.clone(), &account, amount)?;
+					},
+					FeePayer::Burned => {
+						Self::do_mint(asset.clone(), &account, amount)?;
+					},
+					FeePayer::Account(who) => Self::transfer(asset.clone(), who, &account, amount)?,
+				}
+				Self::deposit_event(Event::FeeDistributed { resource_id, destination, amount });
+			}
+
+			if !burn.is_zero() {
+				match payer {
+					FeePayer::BridgeIn
+						if Self::custody_mode(resource_id) == CustodyMode::LockUnlock =>
+					{
+						let custody = Self::custody_balance(resource_id)
+							.checked_sub(&burn)
+							.ok_or(Error::<T, I>::InsufficientCustody)?;
+						Self::burn_from(asset, &Self::custody_account(), burn)?;
+						CustodyBalance::<T, I>::insert(resource_id, custody);
+					},
+					// never minted
+					FeePayer::BridgeIn | FeePayer::Burned => {},
+					FeePayer::Account(who) => {
+						Self::burn_from(asset, who, burn)?;
+					},
+				}
+				Self::deposit_event(Event::FeeDistributed {
+					resource_id,
+					destination: FeeDestination::Burn,
+					amount: burn,
+				});
+			}
+			Ok(burn)
+		}
+
+		/// Whether `who` can be paid `amount` of a fee, zero amounts are skipped anyway.
+		fn can_receive(
+			asset: Option<AssetId<T>>,
+			payer: &FeePayer<T::AccountId>,
+			who: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> bool {
+			use frame_support::traits::tokens::{
+				fungible::Inspect as FInspect, fungibles::Inspect as FsInspect, DepositConsequence,
+				Provenance,
+			};
+
+			if amount.is_zero() {
+				return true
+			}
+			let provenance = match payer {
+				FeePayer::Account(_) => Provenance::Extant,
+				FeePayer::BridgeIn | FeePayer::Burned => Provenance::Minted,
+			};
+			let consequence = match asset {
+				None => <pallet_balances::Pallet<T> as FInspect<_>>::can_deposit(
+					who,
+					convert_balance(amount),
+					provenance,
+				),
+				Some(asset) => <pallet_assets::Pallet<T> as FsInspect<_>>::can_deposit(
+					asset,
+					who,
+					convert_balance(amount),
+					provenance,
+				),
+			};
+			consequence == DepositConsequence::Success
+		}
+
+		fn transfer(
+			asset: Option<AssetId<T>>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			match asset {
+				// Native token
+				None => {
+					<pallet_balances::Pallet<T> as FMutate<_>>::transfer(
+						from,
+						to,
+						convert_balance(amount),
+						Preservation::Expendable,
+					)?;
+				},
+				// pallet assets
+				Some(asset) => {
+					<pallet_assets::Pallet<T> as FsMutate<_>>::transfer(
+						asset,
+						from,
+						to,
+						convert_balance(amount),
+						Preservation::Expendable,
+					)?;
+				},
+			}
+			Ok(())
+		}
 	}
 }
 
-impl<T, B, A> BridgeHandler<B, A, ResourceId> for Pallet<T>
+/// Where the fee of a bridge transfer is paid from.
+enum FeePayer<'a, AccountId> {
+	/// Created by a bridge-in, minted or unlocked from custody
+	BridgeIn,
+	/// Already burned from the sender of a bridge-out
+	Burned,
+	/// Still held by the sender of a bridge-out
+	Account(&'a AccountId),
+}
+
+/// Largest decimals difference whose factor fits in a `u128`
+const MAX_DECIMALS_DIFF: u8 = 38;
+
+fn decimals_factor(diff: u8) -> u128 {
+	10u128.pow(diff.min(MAX_DECIMALS_DIFF) as u32)
+}
+
+/// Converts between the balance types of the bridge and the token pallets, which are the same
+/// type in any sensible runtime.
+fn convert_balance<A: AtLeast32BitUnsigned, B: AtLeast32BitUnsigned>(amount: A) -> B {
+	amount.saturated_into::<u128>().saturated_into()
+}
+
+/// Benchmark setup of `pallet_bridge_transfer` on top of an instance, which maps resources to
+/// the native token without fees.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NativeBenchmarkHelper<T, I = ()>(PhantomData<(T, I)>);
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Config<I>, I: 'static>
+	pallet_bridge_transfer::BenchmarkHelper<T::AccountId, <T as pallet_bridge::Config<I>>::Balance>
+	for NativeBenchmarkHelper<T, I>
+{
+	fn setup_resource(
+		resource_id: ResourceId,
+		who: &T::AccountId,
+		amount: <T as pallet_bridge::Config<I>>::Balance,
+	) {
+		ResourceToAssetInfo::<T, I>::insert(
+			resource_id,
+			AssetInfo {
+				bridge_out_fee: Default::default(),
+				bridge_in_fee: Default::default(),
+				asset: None,
+			},
+		);
+		let ed = <T as pallet_balances::Config>::ExistentialDeposit::get();
+		let _ = <pallet_balances::Pallet<T> as FMutate<_>>::mint_into(
+			who,
+			ed.saturating_add(convert_balance(amount)),
+		);
+	}
+}
+
+/// The assets created by `register_bridged_asset` of an instance, which are owned by its
+/// custody account and only minted by its bridge. Meant as `BridgeOnlyAssets`.
+pub struct RegisteredAssets<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> Contains<<T as pallet_assets::Config>::AssetId>
+	for RegisteredAssets<T, I>
+{
+	fn contains(asset: &<T as pallet_assets::Config>::AssetId) -> bool {
+		use frame_support::traits::fungibles::roles::Inspect;
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::owner(asset.clone()) ==
+			Some(Pallet::<T, I>::custody_account())
+	}
+}
+
+impl<T, I, B, A> BridgeHandler<B, A, ResourceId> for Pallet<T, I>
 where
-	T: Config
+	T: Config<I>
 		+ frame_system::Config<AccountId = A>
-		+ pallet_bridge::Config<Balance = B>
+		+ pallet_bridge::Config<I, Balance = B>
 		+ pallet_assets::Config<Balance = B>
 		+ pallet_balances::Config<Balance = B>,
-	B: Copy + FixedPointOperand + CheckedSub,
+	B: Copy + AtLeast32BitUnsigned + FixedPointOperand + CheckedSub,
+	I: 'static,
 	A: Clone,
 {
 	fn prepare_token_bridge_in(
 		resource_id: ResourceId,
 		who: A,
 		amount: B,
-	) -> Result<B, DispatchError> {
-		let asset_info = Self::resource_to_asset_info(resource_id);
-		match asset_info {
-			None => Err(Error::<T>::InvalidResourceId.into()),
-			// Native token
-			Some(AssetInfo { fee: _, asset: None }) => {
-				Self::deposit_event(Event::TokenBridgeIn {
-					asset_id: None,
-					to: who.clone(),
-					amount,
-				});
-				pallet_balances::Pallet::<T>::mint_into(&who, amount)
-			},
-			// pallet assets
-			Some(AssetInfo { fee: _, asset: Some(asset) }) => {
-				Self::deposit_event(Event::TokenBridgeIn {
-					asset_id: Some(asset.clone()),
-					to: who.clone(),
-					amount,
-				});
-				pallet_assets::Pallet::<T>::mint_into(asset, &who, amount)
-			},
+	) -> Result<BridgeInOutcome<B>, DispatchError> {
+		let (asset, amount, fee) = Self::deduct_bridge_in_fee(resource_id, amount)?;
+		if Self::try_queue_release(resource_id, asset.clone(), &who, amount, false) {
+			// the fee isn't time-locked
+			Self::distribute_fee(resource_id, asset, FeePayer::BridgeIn, fee)?;
+			return Ok(BridgeInOutcome::Queued(amount));
 		}
+		Self::mint(resource_id, asset, &who, amount, fee).map(BridgeInOutcome::Credited)
 	}
-	// Return actual amount to target chain after deduction e.g fee
+	fn quarantine_token_bridge_in(
+		resource_id: ResourceId,
+		who: A,
+		amount: B,
+	) -> Result<B, DispatchError> {
+		let (asset, amount, fee) = Self::deduct_bridge_in_fee(resource_id, amount)?;
+		Self::try_queue_release(resource_id, asset.clone(), &who, amount, true);
+		Self::distribute_fee(resource_id, asset, FeePayer::BridgeIn, fee)?;
+		Ok(amount)
+	}
+	// Return actual amount to target chain after deduction e.g fee, in target chain units
 	fn prepare_token_bridge_out(
 		resource_id: ResourceId,
 		who: A,
 		amount: B,
 	) -> Result<B, DispatchError> {
-		let asset_info = Self::resource_to_asset_info(resource_id);
-		match asset_info {
-			None => Err(Error::<T>::InvalidResourceId.into()),
-			// Native token
-			Some(AssetInfo { fee, asset: None }) => {
-				Self::deposit_event(Event::TokenBridgeOut {
-					asset_id: None,
-					to: who.clone(),
-					amount,
-					fee,
-				});
-				let burn_amount = pallet_balances::Pallet::<T>::burn_from(
-					&who,
-					amount,
-					Precision::Exact,
-					Fortitude::Polite,
-				)?;
-				ensure!(burn_amount > fee, Error::<T>::CannotPayAsFee);
-				pallet_balances::Pallet::<T>::mint_into(&T::TreasuryAccount::get(), fee)?;
-				Ok(burn_amount.checked_sub(&fee).ok_or(ArithmeticError::Overflow)?)
-			},
-			// pallet assets
-			Some(AssetInfo { fee, asset: Some(asset) }) => {
-				Self::deposit_event(Event::TokenBridgeOut {
-					asset_id: Some(asset.clone()),
-					to: who.clone(),
-					amount,
-					fee,
-				});
-				// Since we use Exact approach
-				// Burn amount will always be amount exactly
-				// Otherwise
-				let burn_amount = pallet_assets::Pallet::<T>::burn_from(
-					asset.clone(),
-					&who,
-					amount,
-					Precision::Exact,
-					Fortitude::Polite,
-				)?;
-				ensure!(burn_amount > fee, Error::<T>::CannotPayAsFee);
-				pallet_assets::Pallet::<T>::mint_into(asset, &T::TreasuryAccount::get(), fee)?;
-				Ok(burn_amount.checked_sub(&fee).ok_or(ArithmeticError::Overflow)?)
-			},
+		let AssetInfo { bridge_out_fee, asset, .. } =
+			Self::resource_to_asset_info(resource_id).ok_or(Error::<T, I>::InvalidResourceId)?;
+		ensure!(
+			Self::resource_state(resource_id).allows_outbound(),
+			Error::<T, I>::OutboundDisabled
+		);
+		let native_fee = Self::native_bridge_out_fee(resource_id);
+		let fee = if native_fee.is_some() { Zero::zero() } else { bridge_out_fee.fee(amount) };
+		ensure!(fee.is_zero() || amount > fee, Error::<T, I>::CannotPayAsFee);
+		// dust that can't be represented on the target chain is left with `who`
+		let (_, dust) = Self::to_remote(resource_id, amount - fee)?;
+		let amount = amount - dust;
+		Self::deposit_event(Event::TokenBridgeOut {
+			asset_id: asset.clone(),
+			to: who.clone(),
+			amount,
+			fee,
+		});
+		if let Some(native_fee) = native_fee {
+			Self::distribute_fee(resource_id, None, FeePayer::Account(&who), native_fee)?;
+			Self::deposit_event(Event::NativeFeeCharged {
+				resource_id,
+				who: who.clone(),
+				fee: native_fee,
+			});
+		}
+		let (net, burned_fee) = if Self::custody_mode(resource_id) == CustodyMode::LockUnlock {
+			Self::lock(resource_id, asset, &who, amount, fee)?
+		} else {
+			Self::burn(resource_id, asset, &who, amount, fee)?
+		};
+		BridgedSupplies::<T, I>::mutate(resource_id, |supply| {
+			supply.bridged_out.saturating_accrue(net.saturating_add(burned_fee));
+			supply.fees.saturating_accrue(fee);
+		});
+		Ok(Self::to_remote(resource_id, net)?.0)
+	}
+
+	// charged for the most fee recipients, the distribution may change before dispatch
+	fn prepare_token_bridge_in_weight(resource_id: ResourceId) -> Weight {
+		let n = T::MaxFeeRecipients::get();
+		match Self::resource_to_asset_info(resource_id).and_then(|info| info.asset) {
+			Some(_) => <T as Config<I>>::WeightInfo::bridge_in_asset(n),
+			None => <T as Config<I>>::WeightInfo::bridge_in_native(n),
+		}
+	}
+
+	fn prepare_token_bridge_out_weight(resource_id: ResourceId) -> Weight {
+		let n = T::MaxFeeRecipients::get();
+		match Self::resource_to_asset_info(resource_id).and_then(|info| info.asset) {
+			Some(_) => <T as Config<I>>::WeightInfo::bridge_out_asset(n),
+			None => <T as Config<I>>::WeightInfo::bridge_out_native(n),
 		}
 	}
 }
@@ -0,0 +1,81 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the assets handler pallet.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+
+	/// Asset info of storage version 0, with a flat fee charged on bridge-outs only.
+	#[derive(Encode, Decode)]
+	struct OldAssetInfo<AssetId, Balance> {
+		fee: Balance,
+		asset: Option<AssetId>,
+	}
+
+	/// Moves the flat fee of each resource into its bridge-out fee model. Bridge-ins stay free.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			ResourceToAssetInfo::<T, I>::translate::<
+				OldAssetInfo<
+					<T as pallet_assets::Config>::AssetId,
+					<T as pallet_bridge::Config<I>>::Balance,
+				>,
+				_,
+			>(|_, old| {
+				translated.saturating_inc();
+				Some(AssetInfo {
+					bridge_out_fee: FeeModel::flat(old.fee),
+					bridge_in_fee: Default::default(),
+					asset: old.asset,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((ResourceToAssetInfo::<T, I>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				ResourceToAssetInfo::<T, I>::iter().count() as u32 == count,
+				"resources lost in migration"
+			);
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "wrong storage version");
+			Ok(())
+		}
+	}
+}
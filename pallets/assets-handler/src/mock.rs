@@ -17,12 +17,12 @@
 use crate::{self as pallet_assets_handler};
 use frame_support::{
 	assert_ok, derive_impl, ord_parameter_types, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, SortedMembers},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Everything, SortedMembers},
 	PalletId,
 };
 use hex_literal::hex;
-use pallet_assets_handler::AssetInfo;
-use sp_core::{ConstU16, H256};
+use pallet_assets_handler::{AssetInfo, FeeModel};
+use sp_core::{ConstU16, H160, H256};
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 	BuildStorage,
@@ -96,6 +96,9 @@ parameter_types! {
 	pub const TestChainId: u8 = 5;
 	pub const ProposalLifetime: u64 = 50;
 	pub const TreasuryAccount:u64 = 0x8;
+	pub const StakingRewardPot:u64 = 0x9;
+	pub const BridgePalletId: PalletId = PalletId(*b"litry/bg");
+	pub const CustodyPalletId: PalletId = PalletId(*b"cust/bdg");
 }
 
 impl pallet_bridge::Config for Test {
@@ -103,8 +106,10 @@ impl pallet_bridge::Config for Test {
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Proposal = RuntimeCall;
 	type BridgeChainId = TestChainId;
+	type BridgePalletId = BridgePalletId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxRelayers = ConstU32<16>;
 	type WeightInfo = ();
 }
 
@@ -163,12 +168,51 @@ impl pallet_assets::Config for Test {
 impl pallet_assets_handler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type TreasuryAccount = TreasuryAccount;
+	type StakingRewardPot = StakingRewardPot;
+	type MaxFeeRecipients = ConstU32<4>;
+	type AssetAddress = MockAssetAddress;
+	type GuardianOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ReleaseDelay = ConstU64<10>;
+	type CustodyPalletId = CustodyPalletId;
+	type LocalDecimals = ConstU8<18>;
+	type BridgeOnlyAssets = Everything;
+	type WeightInfo = ();
+}
+
+/// Asset ids as the low bytes of their address
+pub struct MockAssetAddress;
+impl sp_runtime::traits::Convert<u32, H160> for MockAssetAddress {
+	fn convert(asset_id: u32) -> H160 {
+		H160::from_low_u64_be(asset_id as u64)
+	}
+}
+
+/// Maps EVM addresses to their low 8 bytes
+pub struct MockAddressMapping;
+impl pallet_bridge_transfer::AddressMapping<u64> for MockAddressMapping {
+	fn into_account_id(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+parameter_types! {
+	pub EvmLogAddress: H160 = H160::from_low_u64_be(20482);
 }
 
 impl pallet_bridge_transfer::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgeOrigin = pallet_bridge::EnsureBridge<Test>;
 	type TransferNativeMembers = MembersProvider;
 	type BridgeHandler = AssetsHandler;
+	type AddressMapping = MockAddressMapping;
+	type EvmLogAddress = EvmLogAddress;
+	type EvmLogHandler = ();
+	type MaxRecipientLength = ConstU32<64>;
+	type MaxBatchTransfers = ConstU32<16>;
+	type MaxPayloadSize = ConstU32<64>;
+	type EvmCaller = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = crate::NativeBenchmarkHelper<Test>;
 	type WeightInfo = ();
 }
 
@@ -178,7 +222,7 @@ pub const RELAYER_C: u64 = 0x4;
 pub const ENDOWED_BALANCE: u64 = 100_000_000;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let bridge_id = PalletId(*b"litry/bg").into_account_truncating();
+	let bridge_id = BridgePalletId::get().into_account_truncating();
 	let treasury_account: u64 = 0x8;
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
@@ -197,7 +241,11 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		let native_token_asset_info: AssetInfo<
 			<Test as pallet_assets::Config>::AssetId,
 			<Test as pallet_assets::Config>::Balance,
-		> = AssetInfo { fee: 0u64, asset: None };
+		> = AssetInfo {
+			bridge_out_fee: FeeModel::flat(0u64),
+			bridge_in_fee: FeeModel::default(),
+			asset: None,
+		};
 		// Setup asset handler
 		assert_ok!(AssetsHandler::set_resource(
 			RuntimeOrigin::root(),
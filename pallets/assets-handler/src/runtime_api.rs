@@ -0,0 +1,60 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the assets handler pallet.
+
+use crate::{FeeQuote, PendingRelease, ReleaseId};
+use codec::Codec;
+use pallet_bridge::{PalletId, ResourceId};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Each assets handler instance is selected by the `BridgePalletId` of the bridge it
+	/// handles, the queries return `None` for an unknown one.
+	pub trait AssetsHandlerApi<AssetId, AccountId, Balance, BlockNumber>
+	where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Time-locked bridge-ins waiting to be minted
+		fn pending_releases(
+			bridge_id: PalletId,
+		) -> Option<Vec<(ReleaseId, PendingRelease<AssetId, AccountId, Balance, BlockNumber>)>>;
+
+		/// Amount received on the destination chain for bridging out `amount` after fees, with
+		/// the fee and the currency it is charged in. None if the resource is unknown or
+		/// `amount` can't pay the fee
+		fn quote_bridge_out(
+			bridge_id: PalletId,
+			resource_id: ResourceId,
+			amount: Balance,
+		) -> Option<FeeQuote<Balance>>;
+
+		/// Amount received for bridging in `amount` after fees, None if the resource is unknown
+		/// or `amount` can't pay the fee
+		fn quote_bridge_in(
+			bridge_id: PalletId,
+			resource_id: ResourceId,
+			amount: Balance,
+		) -> Option<Balance>;
+
+		/// Tokens of each resource backed by the counterpart chain in burn/mint mode, or locked
+		/// here to back the counterpart chain's supply in lock/unlock mode
+		fn outstanding_supplies(bridge_id: PalletId) -> Option<Vec<(ResourceId, Balance)>>;
+	}
+}
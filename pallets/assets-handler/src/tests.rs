@@ -16,15 +16,16 @@
 
 use super::{
 	mock::{
-		assert_events, new_test_ext, new_test_ext_initialized, Balances, Bridge, BridgeTransfer,
-		NativeTokenResourceId, ProposalLifetime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Test,
-		TreasuryAccount, ENDOWED_BALANCE, RELAYER_A, RELAYER_B, RELAYER_C,
+		assert_events, new_test_ext, new_test_ext_initialized, Assets, AssetsHandler, Balances,
+		Bridge, BridgeTransfer, NativeTokenResourceId, ProposalLifetime, RuntimeCall, RuntimeEvent,
+		RuntimeOrigin, StakingRewardPot, System, Test, TreasuryAccount, ENDOWED_BALANCE, RELAYER_A,
+		RELAYER_B, RELAYER_C,
 	},
 	*,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use hex_literal::hex;
-use sp_runtime::ArithmeticError;
+use sp_runtime::{ArithmeticError, Permill};
 
 fn make_transfer_proposal(to: u64, amount: u64) -> RuntimeCall {
 	let rid = NativeTokenResourceId::get();
@@ -47,7 +48,11 @@ fn transfer() {
 	let native_token_asset_info: AssetInfo<
 		<Test as pallet_assets::Config>::AssetId,
 		<Test as pallet_assets::Config>::Balance,
-	> = AssetInfo { fee: 0u64, asset: None };
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
 
 	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
 		|| {
@@ -65,6 +70,7 @@ fn transfer() {
 					asset_id: None,
 					to: RELAYER_A,
 					amount: 10,
+					fee: 0,
 				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Minted {
 					who: RELAYER_A,
@@ -82,7 +88,11 @@ fn transfer_assets() {
 	let native_token_asset_info: AssetInfo<
 		<Test as pallet_assets::Config>::AssetId,
 		<Test as pallet_assets::Config>::Balance,
-	> = AssetInfo { fee: 10u64, asset: None };
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(10u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
 
 	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
 		|| {
@@ -90,7 +100,7 @@ fn transfer_assets() {
 			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
 				RuntimeOrigin::signed(RELAYER_A),
 				100,
-				dest_account.clone(),
+				dest_account.clone().try_into().unwrap(),
 				dest_bridge_id,
 				resource_id
 			));
@@ -117,6 +127,11 @@ fn transfer_assets() {
 					who: TreasuryAccount::get(),
 					amount: 10,
 				}),
+				RuntimeEvent::AssetsHandler(Event::FeeDistributed {
+					resource_id,
+					destination: FeeDestination::Treasury,
+					amount: 10,
+				}),
 				RuntimeEvent::Bridge(pallet_bridge::Event::FungibleTransfer(
 					dest_bridge_id,
 					1,
@@ -129,6 +144,139 @@ fn transfer_assets() {
 	)
 }
 
+#[test]
+fn fee_model() {
+	let fee = FeeModel::<u64> {
+		flat: 5,
+		proportional: Permill::from_percent(1),
+		min: 10,
+		max: Some(100),
+	};
+	assert_eq!(fee.fee(100), 10);
+	assert_eq!(fee.fee(1_000), 15);
+	assert_eq!(fee.fee(1_000_000), 100);
+	assert_eq!(fee.deduct(1_000), Some((985, 15)));
+	assert_eq!(fee.deduct(10), None);
+	assert_eq!(FeeModel::<u64>::default().deduct(0), Some((0, 0)));
+	assert!(!FeeModel::<u64> { min: 10, max: Some(5), ..Default::default() }.is_valid());
+}
+
+#[test]
+fn bridge_fees_by_direction() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel {
+			flat: 0,
+			proportional: Permill::from_percent(10),
+			min: 5,
+			max: Some(50),
+		},
+		bridge_in_fee: FeeModel::flat(3),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_eq!(
+				AssetsHandler::quote_bridge_out(resource_id, 100).map(|quote| quote.amount),
+				Some(90)
+			);
+			assert_eq!(
+				AssetsHandler::quote_bridge_out(resource_id, 1_000).map(|quote| quote.amount),
+				Some(950)
+			);
+			assert_eq!(
+				AssetsHandler::quote_bridge_out(resource_id, 5).map(|quote| quote.amount),
+				None
+			);
+			assert_eq!(AssetsHandler::quote_bridge_in(resource_id, 10), Some(7));
+			assert_eq!(AssetsHandler::quote_bridge_in([0; 32], 10), None);
+
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				10,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 7);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), ENDOWED_BALANCE + 3);
+			assert_events(vec![
+				RuntimeEvent::AssetsHandler(Event::TokenBridgeIn {
+					asset_id: None,
+					to: RELAYER_A,
+					amount: 7,
+					fee: 3,
+				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Minted {
+					who: RELAYER_A,
+					amount: 7,
+				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Minted {
+					who: TreasuryAccount::get(),
+					amount: 3,
+				}),
+				RuntimeEvent::AssetsHandler(Event::FeeDistributed {
+					resource_id,
+					destination: FeeDestination::Treasury,
+					amount: 3,
+				}),
+			]);
+
+			assert_noop!(
+				BridgeTransfer::transfer(
+					RuntimeOrigin::signed(Bridge::account_id()),
+					RELAYER_A,
+					3,
+					resource_id,
+				),
+				Error::<Test>::CannotPayAsFee
+			);
+
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				1_000,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				resource_id
+			));
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), ENDOWED_BALANCE + 53);
+			assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::FungibleTransfer(
+				dest_bridge_id,
+				1,
+				resource_id,
+				950,
+				vec![1],
+			))]);
+		},
+	)
+}
+
+#[test]
+fn set_resource_rejects_invalid_fee_model() {
+	new_test_ext().execute_with(|| {
+		let asset_info: AssetInfo<
+			<Test as pallet_assets::Config>::AssetId,
+			<Test as pallet_assets::Config>::Balance,
+		> = AssetInfo {
+			bridge_out_fee: FeeModel { min: 10, max: Some(5), ..Default::default() },
+			bridge_in_fee: FeeModel::default(),
+			asset: None,
+		};
+		assert_noop!(
+			AssetsHandler::set_resource(
+				RuntimeOrigin::root(),
+				NativeTokenResourceId::get(),
+				asset_info
+			),
+			Error::<Test>::InvalidFeeModel
+		);
+	})
+}
+
 #[test]
 fn mint_overflow() {
 	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
@@ -136,7 +284,11 @@ fn mint_overflow() {
 	let native_token_asset_info: AssetInfo<
 		<Test as pallet_assets::Config>::AssetId,
 		<Test as pallet_assets::Config>::Balance,
-	> = AssetInfo { fee: 0u64, asset: None };
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
 
 	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
 		|| {
@@ -185,7 +337,11 @@ fn create_successful_transfer_proposal() {
 	let native_token_asset_info: AssetInfo<
 		<Test as pallet_assets::Config>::AssetId,
 		<Test as pallet_assets::Config>::Balance,
-	> = AssetInfo { fee: 0u64, asset: None };
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
 
 	new_test_ext_initialized(src_id, r_id, native_token_asset_info).execute_with(|| {
 		let prop_id = 1;
@@ -253,9 +409,977 @@ fn create_successful_transfer_proposal() {
 				asset_id: None,
 				to: RELAYER_A,
 				amount: 10,
+				fee: 0,
 			}),
 			RuntimeEvent::Balances(pallet_balances::Event::Minted { who: RELAYER_A, amount: 10 }),
 			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalSucceeded(src_id, prop_id)),
 		]);
 	})
 }
+
+#[test]
+fn large_bridge_in_is_time_locked() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			System::set_block_number(1);
+			assert_noop!(
+				AssetsHandler::set_large_transfer_threshold(
+					RuntimeOrigin::signed(RELAYER_A),
+					resource_id,
+					Some(100)
+				),
+				sp_runtime::traits::BadOrigin
+			);
+			assert_ok!(AssetsHandler::set_large_transfer_threshold(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(100)
+			));
+
+			// below the threshold is minted right away
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				100,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 100);
+
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				101,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 100);
+			assert_events(vec![RuntimeEvent::AssetsHandler(Event::ReleaseQueued {
+				release_id: 0,
+				resource_id,
+				to: RELAYER_A,
+				amount: 101,
+				release_at: 11,
+			})]);
+			assert_eq!(AssetsHandler::pending_releases().len(), 1);
+
+			assert_noop!(
+				AssetsHandler::release(RuntimeOrigin::signed(RELAYER_B), 0),
+				Error::<Test>::ReleaseNotDue
+			);
+			System::set_block_number(11);
+			assert_noop!(
+				AssetsHandler::freeze_release(RuntimeOrigin::signed(RELAYER_B), 0),
+				sp_runtime::traits::BadOrigin
+			);
+			assert_ok!(AssetsHandler::freeze_release(RuntimeOrigin::root(), 0));
+			assert_noop!(
+				AssetsHandler::release(RuntimeOrigin::signed(RELAYER_B), 0),
+				Error::<Test>::ReleaseIsFrozen
+			);
+			assert_ok!(AssetsHandler::thaw_release(RuntimeOrigin::root(), 0));
+
+			// anyone can trigger the mint
+			assert_ok!(AssetsHandler::release(RuntimeOrigin::signed(RELAYER_B), 0));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 201);
+			assert_events(vec![
+				RuntimeEvent::AssetsHandler(Event::TokenBridgeIn {
+					asset_id: None,
+					to: RELAYER_A,
+					amount: 101,
+					fee: 0,
+				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Minted {
+					who: RELAYER_A,
+					amount: 101,
+				}),
+				RuntimeEvent::AssetsHandler(Event::Released { release_id: 0 }),
+			]);
+			assert!(AssetsHandler::pending_release(0).is_none());
+		},
+	)
+}
+
+#[test]
+fn pending_releases_are_cancelled_or_released_on_idle() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			System::set_block_number(1);
+			assert_ok!(AssetsHandler::set_large_transfer_threshold(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(100)
+			));
+			for to in [RELAYER_A, RELAYER_B, RELAYER_C] {
+				assert_ok!(BridgeTransfer::transfer(
+					RuntimeOrigin::signed(Bridge::account_id()),
+					to,
+					1000,
+					resource_id,
+				));
+			}
+			assert_ok!(AssetsHandler::cancel_release(RuntimeOrigin::root(), 1));
+			assert_events(vec![RuntimeEvent::AssetsHandler(Event::ReleaseCancelled {
+				release_id: 1,
+			})]);
+			assert_ok!(AssetsHandler::freeze_release(RuntimeOrigin::root(), 2));
+
+			// not due yet
+			AssetsHandler::on_idle(10, Weight::MAX);
+			assert_eq!(AssetsHandler::pending_releases().len(), 2);
+
+			// no weight left
+			AssetsHandler::on_idle(11, Weight::zero());
+			assert_eq!(AssetsHandler::pending_releases().len(), 2);
+
+			AssetsHandler::on_idle(11, Weight::MAX);
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 1000);
+			assert_eq!(Balances::free_balance(RELAYER_B), ENDOWED_BALANCE);
+			assert_eq!(Balances::free_balance(RELAYER_C), ENDOWED_BALANCE);
+			// the frozen release is kept
+			assert_eq!(AssetsHandler::pending_releases().len(), 1);
+			assert!(AssetsHandler::pending_release(2).unwrap().frozen);
+		},
+	)
+}
+
+#[test]
+fn pending_releases_of_removed_resources_are_dropped() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			System::set_block_number(1);
+			assert_ok!(AssetsHandler::set_large_transfer_threshold(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(100)
+			));
+			for to in [RELAYER_A, RELAYER_B] {
+				assert_ok!(BridgeTransfer::transfer(
+					RuntimeOrigin::signed(Bridge::account_id()),
+					to,
+					1000,
+					resource_id,
+				));
+			}
+			assert_ok!(AssetsHandler::remove_resource(RuntimeOrigin::root(), resource_id));
+
+			System::set_block_number(11);
+			assert_noop!(
+				AssetsHandler::release(RuntimeOrigin::signed(RELAYER_B), 0),
+				Error::<Test>::ResourceNotFound
+			);
+			AssetsHandler::on_idle(11, Weight::MAX);
+			assert_events(vec![
+				RuntimeEvent::AssetsHandler(Event::ReleaseCancelled { release_id: 0 }),
+				RuntimeEvent::AssetsHandler(Event::ReleaseCancelled { release_id: 1 }),
+			]);
+			assert!(AssetsHandler::pending_releases().is_empty());
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+			assert_eq!(Balances::free_balance(RELAYER_B), ENDOWED_BALANCE);
+			assert!(!BridgedSupplies::<Test>::contains_key(resource_id));
+		},
+	)
+}
+
+#[test]
+fn quarantined_bridge_ins_are_queued_frozen() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			System::set_block_number(1);
+			// queued even without a large transfer threshold
+			assert_eq!(
+				<AssetsHandler as BridgeHandler<_, _, _>>::quarantine_token_bridge_in(
+					resource_id,
+					RELAYER_A,
+					10
+				),
+				Ok(10)
+			);
+			assert_events(vec![
+				RuntimeEvent::AssetsHandler(Event::ReleaseQueued {
+					release_id: 0,
+					resource_id,
+					to: RELAYER_A,
+					amount: 10,
+					release_at: 11,
+				}),
+				RuntimeEvent::AssetsHandler(Event::ReleaseFrozen { release_id: 0 }),
+			]);
+			assert!(AssetsHandler::pending_release(0).unwrap().frozen);
+
+			AssetsHandler::on_idle(11, Weight::MAX);
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+			assert_noop!(
+				AssetsHandler::release(RuntimeOrigin::signed(RELAYER_A), 0),
+				Error::<Test>::ReleaseIsFrozen
+			);
+
+			assert_ok!(AssetsHandler::thaw_release(RuntimeOrigin::root(), 0));
+			AssetsHandler::on_idle(11, Weight::MAX);
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+			assert!(AssetsHandler::pending_release(0).is_none());
+		},
+	)
+}
+
+#[test]
+fn on_idle_resumes_scanning_pending_releases() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			System::set_block_number(1);
+			assert_ok!(AssetsHandler::set_large_transfer_threshold(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(100)
+			));
+			for to in [RELAYER_A, RELAYER_B, RELAYER_C] {
+				assert_ok!(BridgeTransfer::transfer(
+					RuntimeOrigin::signed(Bridge::account_id()),
+					to,
+					1000,
+					resource_id,
+				));
+			}
+
+			// enough weight for a single release per block
+			let weight = <() as WeightInfo>::release();
+			let mut scanned = Vec::new();
+			for _ in 0..3 {
+				let before: Vec<_> =
+					AssetsHandler::pending_releases().into_iter().map(|(id, _)| id).collect();
+				AssetsHandler::on_idle(11, weight);
+				assert!(ReleaseScanCursor::<Test>::get().is_some());
+				let after: Vec<_> =
+					AssetsHandler::pending_releases().into_iter().map(|(id, _)| id).collect();
+				assert_eq!(after.len(), before.len() - 1);
+				scanned.extend(before.into_iter().filter(|id| !after.contains(id)));
+			}
+			scanned.sort();
+			assert_eq!(scanned, vec![0, 1, 2]);
+
+			// the scan starts over once the end is reached
+			AssetsHandler::on_idle(11, weight);
+			assert!(ReleaseScanCursor::<Test>::get().is_none());
+		},
+	)
+}
+
+#[test]
+fn lock_unlock_custody() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(10u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(AssetsHandler::set_custody_mode(
+				RuntimeOrigin::root(),
+				resource_id,
+				CustodyMode::LockUnlock
+			));
+			let custody_account = AssetsHandler::custody_account();
+			let total_issuance = Balances::total_issuance();
+
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				resource_id
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE - 100);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), ENDOWED_BALANCE + 10);
+			assert_eq!(Balances::free_balance(custody_account), 90);
+			assert_eq!(AssetsHandler::custody_balance(resource_id), 90);
+			assert_eq!(Balances::total_issuance(), total_issuance);
+			assert!(System::events().iter().any(|r| r.event ==
+				RuntimeEvent::AssetsHandler(Event::Locked {
+					resource_id,
+					from: RELAYER_A,
+					amount: 90,
+					custody: 90,
+				})));
+
+			// custody can't go negative
+			assert_noop!(
+				BridgeTransfer::transfer(
+					RuntimeOrigin::signed(Bridge::account_id()),
+					RELAYER_B,
+					91,
+					resource_id,
+				),
+				Error::<Test>::InsufficientCustody
+			);
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_B,
+				30,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_B), 30);
+			assert_eq!(AssetsHandler::custody_balance(resource_id), 60);
+			assert_eq!(Balances::total_issuance(), total_issuance);
+			assert_events(vec![RuntimeEvent::AssetsHandler(Event::Unlocked {
+				resource_id,
+				to: RELAYER_B,
+				amount: 30,
+				custody: 60,
+			})]);
+
+			assert_noop!(
+				AssetsHandler::set_custody_mode(
+					RuntimeOrigin::root(),
+					resource_id,
+					CustodyMode::BurnMint
+				),
+				Error::<Test>::CustodyNotEmpty
+			);
+		},
+	)
+}
+
+#[test]
+fn decimal_scaling() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_noop!(
+				AssetsHandler::set_resource_decimals(RuntimeOrigin::root(), resource_id, Some(60)),
+				Error::<Test>::InvalidDecimals
+			);
+			// two decimals less on the source chain
+			assert_ok!(AssetsHandler::set_resource_decimals(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(16)
+			));
+			assert_eq!(AssetsHandler::quote_bridge_in(resource_id, 7), Some(700));
+			assert_eq!(
+				AssetsHandler::quote_bridge_out(resource_id, 1_234).map(|quote| quote.amount),
+				Some(12)
+			);
+
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				7,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 700);
+
+			// the dust below the source chain precision is kept
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				1_234,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				resource_id
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 700 - 1_200);
+			assert!(System::events().iter().any(|r| r.event ==
+				RuntimeEvent::AssetsHandler(Event::TokenBridgeOut {
+					asset_id: None,
+					to: RELAYER_A,
+					amount: 1_200,
+					fee: 0,
+				})));
+			assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::FungibleTransfer(
+				dest_bridge_id,
+				1,
+				resource_id,
+				12,
+				vec![1],
+			))]);
+		},
+	)
+}
+
+#[test]
+fn bridged_supply_accounting() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let asset_resource_id = [1u8; 32];
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, RELAYER_A, true, 1));
+			assert_ok!(AssetsHandler::set_resource(
+				RuntimeOrigin::root(),
+				asset_resource_id,
+				AssetInfo {
+					bridge_out_fee: FeeModel::flat(3),
+					bridge_in_fee: FeeModel::flat(2),
+					asset: Some(1),
+				}
+			));
+
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				100,
+				asset_resource_id,
+			));
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				50,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				asset_resource_id
+			));
+			assert_eq!(
+				AssetsHandler::bridged_supply(asset_resource_id),
+				BridgedSupply { bridged_in: 100, bridged_out: 47, fees: 5 }
+			);
+			assert_eq!(AssetsHandler::outstanding_supply(asset_resource_id), 53);
+			assert_eq!(Assets::total_supply(1), 53);
+			assert_ok!(AssetsHandler::do_try_state());
+
+			// minted outside the bridge
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(RELAYER_A), 1, RELAYER_B, 5));
+			assert!(AssetsHandler::do_try_state().is_err());
+		},
+	)
+}
+
+#[test]
+fn remove_resource_clears_its_settings() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(AssetsHandler::set_custody_mode(
+				RuntimeOrigin::root(),
+				resource_id,
+				CustodyMode::LockUnlock
+			));
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				resource_id
+			));
+			// the locked tokens would be lost
+			assert_noop!(
+				AssetsHandler::remove_resource(RuntimeOrigin::root(), resource_id),
+				Error::<Test>::CustodyNotEmpty
+			);
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_B,
+				100,
+				resource_id,
+			));
+
+			assert_ok!(AssetsHandler::set_large_transfer_threshold(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(100)
+			));
+			assert_ok!(AssetsHandler::set_resource_state(
+				RuntimeOrigin::root(),
+				resource_id,
+				ResourceState::InboundOnly
+			));
+			assert_ok!(AssetsHandler::set_resource_decimals(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(12)
+			));
+			assert_ok!(AssetsHandler::set_native_bridge_out_fee(
+				RuntimeOrigin::root(),
+				resource_id,
+				Some(5)
+			));
+			assert_ne!(AssetsHandler::bridged_supply(resource_id), BridgedSupply::default());
+
+			assert_ok!(AssetsHandler::remove_resource(RuntimeOrigin::root(), resource_id));
+			assert_eq!(AssetsHandler::resource_to_asset_info(resource_id), None);
+			assert_eq!(AssetsHandler::large_transfer_threshold(resource_id), None);
+			assert_eq!(AssetsHandler::resource_state(resource_id), ResourceState::Active);
+			assert_eq!(AssetsHandler::custody_mode(resource_id), CustodyMode::BurnMint);
+			assert_eq!(AssetsHandler::resource_decimals(resource_id), None);
+			assert_eq!(AssetsHandler::native_bridge_out_fee(resource_id), None);
+			assert_eq!(AssetsHandler::bridged_supply(resource_id), BridgedSupply::default());
+		},
+	)
+}
+
+#[test]
+fn fee_distribution() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(101u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_noop!(
+				AssetsHandler::set_fee_distribution(
+					RuntimeOrigin::root(),
+					FeeDistribution {
+						treasury: Permill::from_percent(50),
+						burn: Permill::from_percent(20),
+						..Default::default()
+					}
+				),
+				Error::<Test>::InvalidFeeDistribution
+			);
+			assert_noop!(
+				AssetsHandler::set_fee_distribution(
+					RuntimeOrigin::root(),
+					FeeDistribution {
+						treasury: Permill::zero(),
+						accounts: vec![(RELAYER_C, Permill::from_percent(20)); 5],
+						..Default::default()
+					}
+				),
+				Error::<Test>::TooManyFeeRecipients
+			);
+			assert_ok!(AssetsHandler::set_fee_distribution(
+				RuntimeOrigin::root(),
+				FeeDistribution {
+					treasury: Permill::from_percent(40),
+					burn: Permill::from_percent(30),
+					staking_pot: Permill::from_percent(20),
+					accounts: vec![(RELAYER_C, Permill::from_percent(10))],
+				}
+			));
+
+			let total_issuance = Balances::total_issuance();
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				1_000,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				resource_id
+			));
+			// the treasury gets the rounding dust
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), ENDOWED_BALANCE + 41);
+			assert_eq!(Balances::free_balance(StakingRewardPot::get()), 20);
+			assert_eq!(Balances::free_balance(RELAYER_C), 10);
+			assert_eq!(Balances::total_issuance(), total_issuance - 1_000 + 71);
+			assert_eq!(
+				AssetsHandler::bridged_supply(resource_id),
+				BridgedSupply { bridged_in: 0, bridged_out: 929, fees: 101 }
+			);
+			for (destination, amount) in [
+				(FeeDestination::Treasury, 41),
+				(FeeDestination::StakingPot, 20),
+				(FeeDestination::Account(RELAYER_C), 10),
+				(FeeDestination::Burn, 30),
+			] {
+				assert!(System::events().iter().any(|r| r.event ==
+					RuntimeEvent::AssetsHandler(Event::FeeDistributed {
+						resource_id,
+						destination: destination.clone(),
+						amount,
+					})));
+			}
+		},
+	)
+}
+
+#[test]
+fn fee_shares_below_min_balance() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let asset_resource_id = [1u8; 32];
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+	let asset_info = |fee| AssetInfo {
+		bridge_out_fee: FeeModel::flat(fee),
+		bridge_in_fee: FeeModel::default(),
+		asset: Some(1),
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, RELAYER_A, true, 10));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(RELAYER_A), 1, RELAYER_A, 1_000));
+			assert_ok!(AssetsHandler::set_fee_distribution(
+				RuntimeOrigin::root(),
+				FeeDistribution {
+					treasury: Permill::from_percent(50),
+					burn: Permill::zero(),
+					staking_pot: Permill::from_percent(25),
+					accounts: vec![(RELAYER_C, Permill::from_percent(25))],
+				}
+			));
+
+			// no destination can receive its share, the whole fee is burned
+			assert_ok!(AssetsHandler::set_resource(
+				RuntimeOrigin::root(),
+				asset_resource_id,
+				asset_info(8)
+			));
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				asset_resource_id
+			));
+			assert_eq!(Assets::balance(1, TreasuryAccount::get()), 0);
+			assert_eq!(Assets::total_supply(1), 900);
+			assert!(System::events().iter().any(|r| r.event ==
+				RuntimeEvent::AssetsHandler(Event::FeeDistributed {
+					resource_id: asset_resource_id,
+					destination: FeeDestination::Burn,
+					amount: 8,
+				})));
+
+			// the shares below the minimum balance go to the treasury
+			assert_ok!(AssetsHandler::set_resource(
+				RuntimeOrigin::root(),
+				asset_resource_id,
+				asset_info(20)
+			));
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				asset_resource_id
+			));
+			assert_eq!(Assets::balance(1, TreasuryAccount::get()), 20);
+			assert_eq!(Assets::balance(1, StakingRewardPot::get()), 0);
+			assert_eq!(Assets::balance(1, RELAYER_C), 0);
+			assert_eq!(Assets::total_supply(1), 820);
+			assert!(System::events().iter().any(|r| r.event ==
+				RuntimeEvent::AssetsHandler(Event::FeeDistributed {
+					resource_id: asset_resource_id,
+					destination: FeeDestination::Treasury,
+					amount: 20,
+				})));
+		},
+	)
+}
+
+#[test]
+fn native_bridge_out_fee() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let asset_resource_id = [1u8; 32];
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, RELAYER_A, true, 1));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(RELAYER_A), 1, RELAYER_A, 10));
+			assert_ok!(AssetsHandler::set_resource(
+				RuntimeOrigin::root(),
+				asset_resource_id,
+				AssetInfo {
+					bridge_out_fee: FeeModel::flat(5),
+					bridge_in_fee: FeeModel::default(),
+					asset: Some(1),
+				}
+			));
+			assert_eq!(AssetsHandler::quote_bridge_out(asset_resource_id, 3), None);
+
+			assert_ok!(AssetsHandler::set_native_bridge_out_fee(
+				RuntimeOrigin::root(),
+				asset_resource_id,
+				Some(20)
+			));
+			assert_eq!(
+				AssetsHandler::quote_bridge_out(asset_resource_id, 3),
+				Some(FeeQuote { amount: 3, fee: 20, currency: FeeCurrency::Native })
+			);
+
+			// bridged in full, the fee is paid in native token
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				3,
+				vec![1].try_into().unwrap(),
+				dest_bridge_id,
+				asset_resource_id
+			));
+			assert_eq!(Assets::balance(1, RELAYER_A), 7);
+			assert_eq!(Assets::balance(1, TreasuryAccount::get()), 0);
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE - 20);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), ENDOWED_BALANCE + 20);
+			assert!(System::events().iter().any(|r| r.event ==
+				RuntimeEvent::AssetsHandler(Event::NativeFeeCharged {
+					resource_id: asset_resource_id,
+					who: RELAYER_A,
+					fee: 20,
+				})));
+			assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::FungibleTransfer(
+				dest_bridge_id,
+				1,
+				asset_resource_id,
+				3,
+				vec![1],
+			))]);
+		},
+	)
+}
+
+#[test]
+fn register_bridged_asset() {
+	use frame_support::traits::fungibles::{metadata::Inspect as MetadataInspect, roles::Inspect};
+
+	let asset_resource_id = [1u8; 32];
+	let asset_info = |asset| AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset,
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsHandler::register_bridged_asset(
+				RuntimeOrigin::root(),
+				asset_resource_id,
+				asset_info(None),
+				b"USD Coin".to_vec(),
+				b"USDC".to_vec(),
+				18,
+				true,
+				1,
+			),
+			Error::<Test>::NotAnAsset
+		);
+		assert_ok!(AssetsHandler::register_bridged_asset(
+			RuntimeOrigin::root(),
+			asset_resource_id,
+			asset_info(Some(7)),
+			b"USD Coin".to_vec(),
+			b"USDC".to_vec(),
+			18,
+			true,
+			1,
+		));
+		assert_events(vec![
+			RuntimeEvent::AssetsHandler(Event::ResourceUpdated {
+				resource_id: asset_resource_id,
+				asset: asset_info(Some(7)),
+			}),
+			RuntimeEvent::AssetsHandler(Event::BridgedAssetRegistered {
+				resource_id: asset_resource_id,
+				asset_id: 7,
+				address: H160::from_low_u64_be(7),
+			}),
+		]);
+		assert_eq!(Assets::owner(7), Some(AssetsHandler::custody_account()));
+		assert_eq!(Assets::name(7), b"USD Coin".to_vec());
+		assert_eq!(Assets::symbol(7), b"USDC".to_vec());
+		assert_eq!(Assets::decimals(7), 18);
+		assert!(RegisteredAssets::<Test>::contains(&7));
+		assert!(!RegisteredAssets::<Test>::contains(&1));
+
+		// sufficient, the recipient needs no native balance
+		assert_ok!(BridgeTransfer::transfer(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			RELAYER_B,
+			10,
+			asset_resource_id,
+		));
+		assert_eq!(Assets::balance(7, RELAYER_B), 10);
+		assert_eq!(AssetsHandler::resource_decimals(asset_resource_id), None);
+
+		// a token with other decimals on the source chain is rescaled to the local decimals
+		let rescaled_resource_id = [2u8; 32];
+		assert_ok!(AssetsHandler::register_bridged_asset(
+			RuntimeOrigin::root(),
+			rescaled_resource_id,
+			asset_info(Some(8)),
+			b"Tether USD".to_vec(),
+			b"USDT".to_vec(),
+			6,
+			true,
+			1,
+		));
+		assert_eq!(Assets::decimals(8), 18);
+		assert_eq!(AssetsHandler::resource_decimals(rescaled_resource_id), Some(6));
+		assert_ok!(BridgeTransfer::transfer(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			RELAYER_B,
+			10,
+			rescaled_resource_id,
+		));
+		assert_eq!(Assets::balance(8, RELAYER_B), 10_000_000_000_000);
+	})
+}
+
+#[test]
+fn resource_states() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo {
+		bridge_out_fee: FeeModel::flat(0u64),
+		bridge_in_fee: FeeModel::default(),
+		asset: None,
+	};
+	let bridge_in = || {
+		BridgeTransfer::transfer(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			RELAYER_A,
+			10,
+			resource_id,
+		)
+	};
+	let bridge_out = || {
+		pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+			RuntimeOrigin::signed(RELAYER_A),
+			10,
+			vec![1].try_into().unwrap(),
+			dest_bridge_id,
+			resource_id,
+		)
+	};
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_noop!(
+				AssetsHandler::set_resource_state(
+					RuntimeOrigin::root(),
+					[0; 32],
+					ResourceState::Paused
+				),
+				Error::<Test>::ResourceNotFound
+			);
+			assert_ok!(AssetsHandler::set_resource_state(
+				RuntimeOrigin::root(),
+				resource_id,
+				ResourceState::Paused
+			));
+			assert_events(vec![RuntimeEvent::AssetsHandler(Event::ResourceStateChanged {
+				resource_id,
+				state: ResourceState::Paused,
+			})]);
+			assert_noop!(bridge_in(), Error::<Test>::InboundDisabled);
+			assert_noop!(bridge_out(), Error::<Test>::OutboundDisabled);
+			assert_eq!(AssetsHandler::quote_bridge_in(resource_id, 10), None);
+
+			// drained inbound only
+			assert_ok!(AssetsHandler::set_resource_state(
+				RuntimeOrigin::root(),
+				resource_id,
+				ResourceState::Deprecated
+			));
+			assert_ok!(bridge_in());
+			assert_noop!(bridge_out(), Error::<Test>::OutboundDisabled);
+			assert_eq!(AssetsHandler::quote_bridge_out(resource_id, 10), None);
+
+			assert_ok!(AssetsHandler::set_resource_state(
+				RuntimeOrigin::root(),
+				resource_id,
+				ResourceState::OutboundOnly
+			));
+			assert_noop!(bridge_in(), Error::<Test>::InboundDisabled);
+			assert_ok!(bridge_out());
+		},
+	)
+}
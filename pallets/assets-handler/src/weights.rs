@@ -0,0 +1,420 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for pallet_assets_handler
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI YET: the storage accesses follow the code and the times
+//! are estimates. Regenerate this file on reference hardware before relying on it, with
+//! `./scripts/benchmark-weights.sh`, which runs:
+
+// Command:
+// ./target/release/canbus
+// benchmark
+// pallet
+// --chain=dev
+// --wasm-execution=compiled
+// --pallet=pallet_assets_handler
+// --extrinsic=*
+// --heap-pages=4096
+// --steps=50
+// --repeat=20
+// --header=./.maintain/header.txt
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/assets-handler/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_assets_handler.
+pub trait WeightInfo {
+	fn set_resource() -> Weight;
+	fn remove_resource() -> Weight;
+	fn set_resource_state() -> Weight;
+	fn set_large_transfer_threshold() -> Weight;
+	fn cancel_release() -> Weight;
+	fn freeze_release() -> Weight;
+	fn thaw_release() -> Weight;
+	fn release() -> Weight;
+	fn set_custody_mode() -> Weight;
+	fn set_resource_decimals() -> Weight;
+	fn set_fee_distribution(n: u32) -> Weight;
+	fn set_native_bridge_out_fee() -> Weight;
+	fn register_bridged_asset() -> Weight;
+	fn bridge_in_native(n: u32, ) -> Weight;
+	fn bridge_in_asset(n: u32, ) -> Weight;
+	fn bridge_out_native(n: u32, ) -> Weight;
+	fn bridge_out_asset(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_assets_handler using the Litentry node and recommended hardware.
+pub struct LitentryWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
+	// Storage: AssetsHandler ResourceToAssetInfo (r:0 w:1)
+	fn set_resource() -> Weight {
+		Weight::from_parts(12_734_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler CustodyBalance (r:1 w:0)
+	// Storage: AssetsHandler ResourceToAssetInfo (r:0 w:1)
+	// Storage: AssetsHandler LargeTransferThreshold (r:0 w:1)
+	// Storage: AssetsHandler ResourceStates (r:0 w:1)
+	// Storage: AssetsHandler ResourceCustodyMode (r:0 w:1)
+	// Storage: AssetsHandler ResourceDecimals (r:0 w:1)
+	// Storage: AssetsHandler NativeBridgeOutFee (r:0 w:1)
+	// Storage: AssetsHandler BridgedSupplies (r:0 w:1)
+	fn remove_resource() -> Weight {
+		Weight::from_parts(11_928_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:0 w:1)
+	fn set_resource_state() -> Weight {
+		Weight::from_parts(13_904_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler LargeTransferThreshold (r:0 w:1)
+	fn set_large_transfer_threshold() -> Weight {
+		Weight::from_parts(11_741_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	fn cancel_release() -> Weight {
+		Weight::from_parts(15_206_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	fn freeze_release() -> Weight {
+		Weight::from_parts(15_873_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	fn thaw_release() -> Weight {
+		Weight::from_parts(15_790_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release() -> Weight {
+		Weight::from_parts(48_537_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: AssetsHandler CustodyBalance (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:0 w:1)
+	fn set_custody_mode() -> Weight {
+		Weight::from_parts(14_052_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler ResourceDecimals (r:0 w:1)
+	fn set_resource_decimals() -> Weight {
+		Weight::from_parts(11_690_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler BridgeFeeDistribution (r:0 w:1)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_fee_distribution(n: u32, ) -> Weight {
+		Weight::from_parts(12_398_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(211_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler NativeBridgeOutFee (r:0 w:1)
+	fn set_native_bridge_out_fee() -> Weight {
+		Weight::from_parts(11_817_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: AssetsHandler ResourceDecimals (r:0 w:1)
+	// Storage: AssetsHandler ResourceToAssetInfo (r:0 w:1)
+	fn register_bridged_asset() -> Weight {
+		Weight::from_parts(33_506_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: AssetsHandler LargeTransferThreshold (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: System Account (r:19 w:19)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_in_native(n: u32, ) -> Weight {
+		Weight::from_parts(91_384_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(12_500_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: AssetsHandler LargeTransferThreshold (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:19 w:19)
+	// Storage: System Account (r:19 w:19)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_in_asset(n: u32, ) -> Weight {
+		Weight::from_parts(98_627_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(20_000_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler NativeBridgeOutFee (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: System Account (r:19 w:19)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_out_native(n: u32, ) -> Weight {
+		Weight::from_parts(84_905_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(12_500_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler NativeBridgeOutFee (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:19 w:19)
+	// Storage: System Account (r:18 w:18)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_out_asset(n: u32, ) -> Weight {
+		Weight::from_parts(89_182_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(20_000_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: AssetsHandler ResourceToAssetInfo (r:0 w:1)
+	fn set_resource() -> Weight {
+		Weight::from_parts(12_734_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler CustodyBalance (r:1 w:0)
+	// Storage: AssetsHandler ResourceToAssetInfo (r:0 w:1)
+	// Storage: AssetsHandler LargeTransferThreshold (r:0 w:1)
+	// Storage: AssetsHandler ResourceStates (r:0 w:1)
+	// Storage: AssetsHandler ResourceCustodyMode (r:0 w:1)
+	// Storage: AssetsHandler ResourceDecimals (r:0 w:1)
+	// Storage: AssetsHandler NativeBridgeOutFee (r:0 w:1)
+	// Storage: AssetsHandler BridgedSupplies (r:0 w:1)
+	fn remove_resource() -> Weight {
+		Weight::from_parts(11_928_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:0 w:1)
+	fn set_resource_state() -> Weight {
+		Weight::from_parts(13_904_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler LargeTransferThreshold (r:0 w:1)
+	fn set_large_transfer_threshold() -> Weight {
+		Weight::from_parts(11_741_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	fn cancel_release() -> Weight {
+		Weight::from_parts(15_206_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	fn freeze_release() -> Weight {
+		Weight::from_parts(15_873_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	fn thaw_release() -> Weight {
+		Weight::from_parts(15_790_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler PendingReleases (r:1 w:1)
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release() -> Weight {
+		Weight::from_parts(48_537_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: AssetsHandler CustodyBalance (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:0 w:1)
+	fn set_custody_mode() -> Weight {
+		Weight::from_parts(14_052_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler ResourceDecimals (r:0 w:1)
+	fn set_resource_decimals() -> Weight {
+		Weight::from_parts(11_690_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler BridgeFeeDistribution (r:0 w:1)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_fee_distribution(n: u32, ) -> Weight {
+		Weight::from_parts(12_398_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(211_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetsHandler NativeBridgeOutFee (r:0 w:1)
+	fn set_native_bridge_out_fee() -> Weight {
+		Weight::from_parts(11_817_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: AssetsHandler ResourceDecimals (r:0 w:1)
+	// Storage: AssetsHandler ResourceToAssetInfo (r:0 w:1)
+	fn register_bridged_asset() -> Weight {
+		Weight::from_parts(33_506_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: AssetsHandler LargeTransferThreshold (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: System Account (r:19 w:19)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_in_native(n: u32, ) -> Weight {
+		Weight::from_parts(91_384_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(12_500_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: AssetsHandler LargeTransferThreshold (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:19 w:19)
+	// Storage: System Account (r:19 w:19)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_in_asset(n: u32, ) -> Weight {
+		Weight::from_parts(98_627_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(20_000_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler NativeBridgeOutFee (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: System Account (r:19 w:19)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_out_native(n: u32, ) -> Weight {
+		Weight::from_parts(84_905_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(12_500_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AssetsHandler ResourceToAssetInfo (r:1 w:0)
+	// Storage: AssetsHandler ResourceStates (r:1 w:0)
+	// Storage: AssetsHandler NativeBridgeOutFee (r:1 w:0)
+	// Storage: AssetsHandler ResourceDecimals (r:1 w:0)
+	// Storage: AssetsHandler BridgeFeeDistribution (r:1 w:0)
+	// Storage: AssetsHandler ResourceCustodyMode (r:1 w:0)
+	// Storage: AssetsHandler BridgedSupplies (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:19 w:19)
+	// Storage: System Account (r:18 w:18)
+	/// The range of component `n` is `[0, 16]`.
+	fn bridge_out_asset(n: u32, ) -> Weight {
+		Weight::from_parts(89_182_000 as u64, 0)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(20_000_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+}
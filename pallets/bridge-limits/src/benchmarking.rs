@@ -0,0 +1,105 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! bridge-limits benchmark file
+
+#![cfg(feature = "runtime-benchmarks")]
+#![allow(clippy::duplicated_attributes)]
+use super::*;
+use crate::Pallet as BridgeLimits;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+
+const SEED: u32 = 0;
+const RESOURCE_ID: ResourceId = [1u8; 32];
+const BRIDGE_ID: PalletId = PalletId(*b"bench/bg");
+
+/// An allowlisted account of tier 1, with a limit on `RESOURCE_ID` and some usage left.
+fn setup_account<T: Config>() -> T::AccountId {
+	let who: T::AccountId = account("who", 0, SEED);
+	AllowlistEnabled::<T>::put(true);
+	Allowlist::<T>::insert(&who, ());
+	KycTiers::<T>::insert(&who, 1);
+	PeriodLimits::<T>::insert(RESOURCE_ID, 1, T::Balance::from(1_000u32));
+	PeriodUsages::<T>::insert(
+		(BRIDGE_ID, &who, RESOURCE_ID),
+		PeriodUsage { period: Zero::zero(), amount: T::Balance::from(100u32) },
+	);
+	who
+}
+
+benchmarks! {
+	set_allowlist_enabled{
+		let origin = T::ManagerOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+	}:_<T::RuntimeOrigin>(origin, true)
+	verify{
+		assert!(AllowlistEnabled::<T>::get());
+	}
+
+	set_allowlisted{
+		let origin = T::ManagerOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let who: T::AccountId = account("who", 0, SEED);
+	}:_<T::RuntimeOrigin>(origin, who.clone(), true)
+	verify{
+		assert!(Allowlist::<T>::contains_key(&who));
+	}
+
+	set_blocked{
+		let origin = T::ManagerOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let who: T::AccountId = account("who", 0, SEED);
+	}:_<T::RuntimeOrigin>(origin, who.clone(), true)
+	verify{
+		assert!(Blocklist::<T>::contains_key(&who));
+	}
+
+	set_kyc_tier{
+		let origin = T::ManagerOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let who: T::AccountId = account("who", 0, SEED);
+	}:_<T::RuntimeOrigin>(origin, who.clone(), 2)
+	verify{
+		assert_eq!(KycTiers::<T>::get(&who), 2);
+	}
+
+	set_period_limit{
+		let origin = T::ManagerOrigin::try_successful_origin().map_err(|_| "BadOrigin")?;
+		let limit = Some(T::Balance::from(1_000u32));
+	}:_<T::RuntimeOrigin>(origin, RESOURCE_ID, 1, limit)
+	verify{
+		assert_eq!(PeriodLimits::<T>::get(RESOURCE_ID, 1), limit);
+	}
+
+	check_bridge_out{
+		let who = setup_account::<T>();
+	}:{
+		BridgeLimits::<T>::ensure_allowed(&who)?;
+		BridgeLimits::<T>::record_bridge_out(BRIDGE_ID, &who, RESOURCE_ID, T::Balance::from(100u32))?;
+	}
+	verify{
+		assert_eq!(BridgeLimits::<T>::period_usage((BRIDGE_ID, &who, RESOURCE_ID)).amount, T::Balance::from(200u32));
+	}
+
+	check_bridge_in{
+		let who: T::AccountId = account("who", 0, SEED);
+		Blocklist::<T>::insert(&who, ());
+		let mut blocked = false;
+	}:{
+		blocked = BridgeLimits::<T>::is_blocked(&who);
+	}
+	verify{
+		assert!(blocked);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
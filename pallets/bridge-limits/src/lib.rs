@@ -0,0 +1,451 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! A pallet gating bridge transfers, for controlled launches.
+//!
+//! Blocklisted accounts can't bridge out and, once the allowlist is enabled, neither can
+//! accounts missing from it. Bridge-outs of each account are also capped per bridge, resource
+//! and `LimitPeriod`, by the limit of the account's KYC tier.
+//!
+//! Usages, and so the limits, are kept per bridge on purpose: each bridge has its own relayers
+//! and custody, so capping each of them on its own bounds what a single faulty bridge can
+//! move. An account can thus bridge out its limit through every bridge in a period.
+//!
+//! Bridge-ins never fail: they were already paid for on the source chain, so failing them
+//! would only leave the tokens stuck there. Bridge-ins to blocklisted accounts are quarantined
+//! instead, queued as a frozen release of the `BridgeHandler` until its guardian thaws or
+//! cancels it.
+//!
+//! The pallet is plugged into `pallet_bridge_transfer` as its `TransferNativeMembers`, and
+//! [`LimitedBridgeHandler`] wraps its `BridgeHandler` to check bridge-outs and quarantine
+//! bridge-ins.
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{SortedMembers, StorageVersion},
+	PalletId,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_bridge_transfer::{BridgeHandler, BridgeInOutcome};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, CheckedAdd, MaybeSerializeDeserialize, Saturating, Zero},
+	DispatchError,
+};
+use sp_std::{fmt::Debug, prelude::*};
+pub use weights::WeightInfo;
+type ResourceId = pallet_bridge::ResourceId;
+
+/// KYC tier of an account, 0 for accounts without KYC.
+pub type KycTier = u8;
+
+/// Amount an account bridged out of a resource in a limit period.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Debug, Decode, TypeInfo, MaxEncodedLen)]
+pub struct PeriodUsage<BlockNumber, Balance> {
+	/// Index of the period, i.e. the block number divided by the period length
+	pub period: BlockNumber,
+	pub amount: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Balance type of the bridged amounts
+		type Balance: Parameter
+			+ Member
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ MaxEncodedLen;
+
+		/// Origin managing the lists, KYC tiers and limits
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Length of the periods bridge-out limits apply to, e.g. a day
+		#[pallet::constant]
+		type LimitPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for the extrinsics and bridge checks of this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Whether only allowlisted accounts can bridge
+	#[pallet::storage]
+	#[pallet::getter(fn allowlist_enabled)]
+	pub type AllowlistEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	pub type Allowlist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	pub type Blocklist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn kyc_tier)]
+	pub type KycTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, KycTier, ValueQuery>;
+
+	/// Maximum bridge-out of a resource per account, bridge and period, by KYC tier. Unlimited
+	/// if unset.
+	#[pallet::storage]
+	#[pallet::getter(fn period_limit)]
+	pub type PeriodLimits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ResourceId, Twox64Concat, KycTier, T::Balance>;
+
+	/// Bridge-outs of an account by bridge, identified by its pallet id, and resource. Usages
+	/// of past periods are pruned when blocks have weight left.
+	#[pallet::storage]
+	#[pallet::getter(fn period_usage)]
+	pub type PeriodUsages<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, PalletId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, ResourceId>,
+		),
+		PeriodUsage<BlockNumberFor<T>, T::Balance>,
+		ValueQuery,
+	>;
+
+	/// Raw key of the last usage scanned for pruning, the next scan resumes after it
+	#[pallet::storage]
+	pub type UsageScanCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		AllowlistToggled {
+			enabled: bool,
+		},
+		AllowlistUpdated {
+			who: T::AccountId,
+			allowed: bool,
+		},
+		BlocklistUpdated {
+			who: T::AccountId,
+			blocked: bool,
+		},
+		KycTierSet {
+			who: T::AccountId,
+			tier: KycTier,
+		},
+		/// Bridge-out limit of a resource for a KYC tier changed, None means unlimited
+		PeriodLimitSet {
+			resource_id: ResourceId,
+			tier: KycTier,
+			limit: Option<T::Balance>,
+		},
+		/// A bridge-in to a blocklisted account was queued frozen by the bridge's handler
+		BridgeInQuarantined {
+			bridge_id: PalletId,
+			resource_id: ResourceId,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is blocklisted or missing from the enabled allowlist
+		NotAllowed,
+		/// The bridge-out exceeds what the account has left in the current period
+		LimitExceeded,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Removes usages of past periods with the remaining weight of the block. Each call
+		/// scans on from where the previous one stopped, starting over once all were scanned.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// 1 read, 1 write: the scanned usage, removed if stale
+			let usage_weight = T::DbWeight::get().reads_writes(1, 1);
+			// 1 read, 1 write: `usage_scan_cursor`
+			let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+			if used_weight.any_gt(remaining_weight) {
+				return Weight::zero()
+			}
+
+			let period = Self::current_period();
+			let mut usages = match UsageScanCursor::<T>::take() {
+				Some(cursor) => PeriodUsages::<T>::iter_from(cursor),
+				None => PeriodUsages::<T>::iter(),
+			};
+			let mut stale = Vec::new();
+			loop {
+				if used_weight.saturating_add(usage_weight).any_gt(remaining_weight) {
+					UsageScanCursor::<T>::put(usages.last_raw_key().to_vec());
+					break;
+				}
+				let Some((key, usage)) = usages.next() else { break };
+				used_weight.saturating_accrue(usage_weight);
+				if usage.period < period {
+					stale.push(key);
+				}
+			}
+
+			for key in stale {
+				PeriodUsages::<T>::remove(key);
+			}
+			used_weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enables or disables the allowlist. The blocklist always applies.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_allowlist_enabled())]
+		pub fn set_allowlist_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			AllowlistEnabled::<T>::put(enabled);
+			Self::deposit_event(Event::AllowlistToggled { enabled });
+			Ok(())
+		}
+
+		/// Adds an account to or removes it from the allowlist.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_allowlisted())]
+		pub fn set_allowlisted(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			allowed: bool,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			if allowed {
+				Allowlist::<T>::insert(&who, ());
+			} else {
+				Allowlist::<T>::remove(&who);
+			}
+			Self::deposit_event(Event::AllowlistUpdated { who, allowed });
+			Ok(())
+		}
+
+		/// Adds an account to or removes it from the blocklist.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_blocked())]
+		pub fn set_blocked(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			blocked: bool,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			if blocked {
+				Blocklist::<T>::insert(&who, ());
+			} else {
+				Blocklist::<T>::remove(&who);
+			}
+			Self::deposit_event(Event::BlocklistUpdated { who, blocked });
+			Ok(())
+		}
+
+		/// Sets the KYC tier of an account, 0 removes it.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_kyc_tier())]
+		pub fn set_kyc_tier(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			tier: KycTier,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			if tier.is_zero() {
+				KycTiers::<T>::remove(&who);
+			} else {
+				KycTiers::<T>::insert(&who, tier);
+			}
+			Self::deposit_event(Event::KycTierSet { who, tier });
+			Ok(())
+		}
+
+		/// Sets how much an account of a KYC tier can bridge out of a resource per period,
+		/// `None` for no limit.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_period_limit())]
+		pub fn set_period_limit(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			tier: KycTier,
+			limit: Option<T::Balance>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			PeriodLimits::<T>::set(resource_id, tier, limit);
+			Self::deposit_event(Event::PeriodLimitSet { resource_id, tier, limit });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn is_blocked(who: &T::AccountId) -> bool {
+			Blocklist::<T>::contains_key(who)
+		}
+
+		/// Whether `who` can bridge at all.
+		pub fn is_allowed(who: &T::AccountId) -> bool {
+			!Self::is_blocked(who) &&
+				(!Self::allowlist_enabled() || Allowlist::<T>::contains_key(who))
+		}
+
+		pub fn ensure_allowed(who: &T::AccountId) -> DispatchResult {
+			ensure!(Self::is_allowed(who), Error::<T>::NotAllowed);
+			Ok(())
+		}
+
+		/// Amount `who` can still bridge out of a resource through a bridge in the current
+		/// period, None if unlimited.
+		pub fn remaining_allowance(
+			bridge_id: PalletId,
+			who: &T::AccountId,
+			resource_id: ResourceId,
+		) -> Option<T::Balance> {
+			let limit = Self::period_limit(resource_id, Self::kyc_tier(who))?;
+			let usage = Self::period_usage((bridge_id, who, resource_id));
+			if usage.period != Self::current_period() {
+				return Some(limit)
+			}
+			Some(limit.saturating_sub(usage.amount))
+		}
+
+		/// Counts `amount` against the bridge-out limit of `who` on a bridge.
+		pub fn record_bridge_out(
+			bridge_id: PalletId,
+			who: &T::AccountId,
+			resource_id: ResourceId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let Some(limit) = Self::period_limit(resource_id, Self::kyc_tier(who)) else {
+				return Ok(())
+			};
+			let period = Self::current_period();
+			PeriodUsages::<T>::try_mutate((bridge_id, who, resource_id), |usage| {
+				if usage.period != period {
+					*usage = PeriodUsage { period, amount: Zero::zero() };
+				}
+				let used = usage.amount.checked_add(&amount).ok_or(Error::<T>::LimitExceeded)?;
+				ensure!(used <= limit, Error::<T>::LimitExceeded);
+				usage.amount = used;
+				Ok(())
+			})
+		}
+
+		fn current_period() -> BlockNumberFor<T> {
+			let length = T::LimitPeriod::get();
+			if length.is_zero() {
+				return Zero::zero()
+			}
+			frame_system::Pallet::<T>::block_number() / length
+		}
+	}
+}
+
+/// Members are the accounts allowed to bridge. Only the allowlist can be listed, as everyone
+/// else not blocklisted is a member while it's disabled.
+impl<T: Config> SortedMembers<T::AccountId> for Pallet<T> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		let mut members: Vec<_> = Allowlist::<T>::iter_keys()
+			.filter(|who| !Blocklist::<T>::contains_key(who))
+			.collect();
+		members.sort();
+		members
+	}
+
+	fn contains(who: &T::AccountId) -> bool {
+		Self::is_allowed(who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &T::AccountId) {
+		Allowlist::<T>::insert(who, ());
+	}
+}
+
+/// Checks bridge-outs against the lists and limits of this pallet before handing them to the
+/// `BridgeHandler` `H`. `B` is the pallet id of the bridge, which keeps usages of the bridges
+/// apart. Bridge-ins are passed through, or quarantined by `H` for blocklisted accounts.
+pub struct LimitedBridgeHandler<T, H, B>(PhantomData<(T, H, B)>);
+
+impl<T, H, B> BridgeHandler<T::Balance, T::AccountId, ResourceId> for LimitedBridgeHandler<T, H, B>
+where
+	T: Config,
+	H: BridgeHandler<T::Balance, T::AccountId, ResourceId>,
+	B: Get<PalletId>,
+{
+	fn prepare_token_bridge_in(
+		resource_id: ResourceId,
+		who: T::AccountId,
+		amount: T::Balance,
+	) -> Result<BridgeInOutcome<T::Balance>, DispatchError> {
+		if !Pallet::<T>::is_blocked(&who) {
+			return H::prepare_token_bridge_in(resource_id, who, amount)
+		}
+		let queued = H::quarantine_token_bridge_in(resource_id, who.clone(), amount)?;
+		Pallet::<T>::deposit_event(Event::BridgeInQuarantined {
+			bridge_id: B::get(),
+			resource_id,
+			who,
+			amount: queued,
+		});
+		Ok(BridgeInOutcome::Queued(queued))
+	}
+
+	fn quarantine_token_bridge_in(
+		resource_id: ResourceId,
+		who: T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		H::quarantine_token_bridge_in(resource_id, who, amount)
+	}
+
+	fn prepare_token_bridge_out(
+		resource_id: ResourceId,
+		who: T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Pallet::<T>::ensure_allowed(&who)?;
+		Pallet::<T>::record_bridge_out(B::get(), &who, resource_id, amount)?;
+		H::prepare_token_bridge_out(resource_id, who, amount)
+	}
+
+	fn prepare_token_bridge_in_weight(resource_id: ResourceId) -> Weight {
+		T::WeightInfo::check_bridge_in()
+			.saturating_add(H::prepare_token_bridge_in_weight(resource_id))
+	}
+
+	fn prepare_token_bridge_out_weight(resource_id: ResourceId) -> Weight {
+		T::WeightInfo::check_bridge_out()
+			.saturating_add(H::prepare_token_bridge_out_weight(resource_id))
+	}
+}
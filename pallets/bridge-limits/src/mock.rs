@@ -0,0 +1,125 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_bridge_limits};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+	weights::{constants::RocksDbWeight, Weight},
+	PalletId,
+};
+use pallet_bridge::ResourceId;
+use pallet_bridge_transfer::BridgeInOutcome;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError,
+};
+type Block = frame_system::mocking::MockBlock<Test>;
+
+type AccountId = u64;
+type Balance = u64;
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		BridgeLimits: pallet_bridge_limits,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = RocksDbWeight;
+	type PalletInfo = PalletInfo;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_bridge_limits::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type ManagerOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type LimitPeriod = ConstU64<10>;
+	type WeightInfo = ();
+}
+
+/// Hands over bridged amounts unchanged
+pub struct MockBridgeHandler;
+impl pallet_bridge_transfer::BridgeHandler<Balance, AccountId, ResourceId> for MockBridgeHandler {
+	fn prepare_token_bridge_in(
+		_: ResourceId,
+		_: AccountId,
+		amount: Balance,
+	) -> Result<BridgeInOutcome<Balance>, DispatchError> {
+		Ok(BridgeInOutcome::Credited(amount))
+	}
+
+	fn quarantine_token_bridge_in(
+		_: ResourceId,
+		_: AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(amount)
+	}
+
+	fn prepare_token_bridge_out(
+		_: ResourceId,
+		_: AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(amount)
+	}
+
+	fn prepare_token_bridge_in_weight(_: ResourceId) -> Weight {
+		Weight::from_parts(1, 0)
+	}
+
+	fn prepare_token_bridge_out_weight(_: ResourceId) -> Weight {
+		Weight::from_parts(2, 0)
+	}
+}
+
+parameter_types! {
+	pub const BridgeId: PalletId = PalletId(*b"litry/bg");
+	pub const OtherBridgeId: PalletId = PalletId(*b"can/lcbg");
+}
+
+pub type LimitedHandler =
+	pallet_bridge_limits::LimitedBridgeHandler<Test, MockBridgeHandler, BridgeId>;
+pub type OtherLimitedHandler =
+	pallet_bridge_limits::LimitedBridgeHandler<Test, MockBridgeHandler, OtherBridgeId>;
+
+pub const ALICE: AccountId = 0x1;
+pub const BOB: AccountId = 0x2;
+pub const RESOURCE_ID: ResourceId = [1u8; 32];
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| frame_system::Pallet::<Test>::set_block_number(1));
+	ext
+}
@@ -0,0 +1,266 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	mock::{
+		new_test_ext, BridgeId, BridgeLimits, LimitedHandler, OtherBridgeId, OtherLimitedHandler,
+		RuntimeEvent, RuntimeOrigin, System, Test, ALICE, BOB, RESOURCE_ID,
+	},
+	*,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn everyone_is_allowed_by_default() {
+	new_test_ext().execute_with(|| {
+		assert!(<BridgeLimits as SortedMembers<u64>>::contains(&ALICE));
+		assert_eq!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 100), Ok(100));
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in(RESOURCE_ID, ALICE, 100),
+			Ok(BridgeInOutcome::Credited(100))
+		);
+	})
+}
+
+#[test]
+fn only_manager_can_configure() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeLimits::set_allowlist_enabled(RuntimeOrigin::signed(ALICE), true),
+			BadOrigin
+		);
+		assert_noop!(
+			BridgeLimits::set_allowlisted(RuntimeOrigin::signed(ALICE), ALICE, true),
+			BadOrigin
+		);
+		assert_noop!(BridgeLimits::set_blocked(RuntimeOrigin::signed(ALICE), BOB, true), BadOrigin);
+		assert_noop!(BridgeLimits::set_kyc_tier(RuntimeOrigin::signed(ALICE), ALICE, 3), BadOrigin);
+		assert_noop!(
+			BridgeLimits::set_period_limit(RuntimeOrigin::signed(ALICE), RESOURCE_ID, 0, None),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn blocklist_stops_bridge_outs() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeLimits::set_blocked(RuntimeOrigin::root(), ALICE, true));
+		System::assert_last_event(RuntimeEvent::BridgeLimits(Event::BlocklistUpdated {
+			who: ALICE,
+			blocked: true,
+		}));
+
+		assert!(!<BridgeLimits as SortedMembers<u64>>::contains(&ALICE));
+		assert!(<BridgeLimits as SortedMembers<u64>>::contains(&BOB));
+		assert_noop!(
+			LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 100),
+			Error::<Test>::NotAllowed
+		);
+		// bridge-ins were paid for on the source chain already, they are quarantined instead
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in(RESOURCE_ID, ALICE, 100),
+			Ok(BridgeInOutcome::Queued(100))
+		);
+		System::assert_last_event(RuntimeEvent::BridgeLimits(Event::BridgeInQuarantined {
+			bridge_id: BridgeId::get(),
+			resource_id: RESOURCE_ID,
+			who: ALICE,
+			amount: 100,
+		}));
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in(RESOURCE_ID, BOB, 100),
+			Ok(BridgeInOutcome::Credited(100))
+		);
+
+		assert_ok!(BridgeLimits::set_blocked(RuntimeOrigin::root(), ALICE, false));
+		assert_eq!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 100), Ok(100));
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in(RESOURCE_ID, ALICE, 100),
+			Ok(BridgeInOutcome::Credited(100))
+		);
+	})
+}
+
+#[test]
+fn allowlist_only_applies_when_enabled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeLimits::set_allowlisted(RuntimeOrigin::root(), ALICE, true));
+		assert!(<BridgeLimits as SortedMembers<u64>>::contains(&BOB));
+
+		assert_ok!(BridgeLimits::set_allowlist_enabled(RuntimeOrigin::root(), true));
+		System::assert_last_event(RuntimeEvent::BridgeLimits(Event::AllowlistToggled {
+			enabled: true,
+		}));
+		assert!(<BridgeLimits as SortedMembers<u64>>::contains(&ALICE));
+		assert!(!<BridgeLimits as SortedMembers<u64>>::contains(&BOB));
+		assert_eq!(<BridgeLimits as SortedMembers<u64>>::sorted_members(), vec![ALICE]);
+		assert_noop!(
+			LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, BOB, 100),
+			Error::<Test>::NotAllowed
+		);
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in(RESOURCE_ID, BOB, 100),
+			Ok(BridgeInOutcome::Credited(100))
+		);
+
+		// the blocklist wins over the allowlist
+		assert_ok!(BridgeLimits::set_blocked(RuntimeOrigin::root(), ALICE, true));
+		assert!(!<BridgeLimits as SortedMembers<u64>>::contains(&ALICE));
+		assert!(<BridgeLimits as SortedMembers<u64>>::sorted_members().is_empty());
+	})
+}
+
+#[test]
+fn bridge_outs_are_limited_by_kyc_tier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeLimits::set_period_limit(
+			RuntimeOrigin::root(),
+			RESOURCE_ID,
+			0,
+			Some(100)
+		));
+		assert_ok!(BridgeLimits::set_period_limit(
+			RuntimeOrigin::root(),
+			RESOURCE_ID,
+			1,
+			Some(500)
+		));
+		System::assert_last_event(RuntimeEvent::BridgeLimits(Event::PeriodLimitSet {
+			resource_id: RESOURCE_ID,
+			tier: 1,
+			limit: Some(500),
+		}));
+		assert_ok!(BridgeLimits::set_kyc_tier(RuntimeOrigin::root(), ALICE, 1));
+
+		assert_eq!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 300), Ok(300));
+		assert_eq!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, BOB, 60), Ok(60));
+		assert_eq!(
+			BridgeLimits::remaining_allowance(BridgeId::get(), &ALICE, RESOURCE_ID),
+			Some(200)
+		);
+		assert_eq!(BridgeLimits::remaining_allowance(BridgeId::get(), &BOB, RESOURCE_ID), Some(40));
+
+		assert_noop!(
+			LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 201),
+			Error::<Test>::LimitExceeded
+		);
+		assert_noop!(
+			LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, BOB, 41),
+			Error::<Test>::LimitExceeded
+		);
+		assert_eq!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 200), Ok(200));
+
+		// bridge-ins and other resources aren't limited
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in(RESOURCE_ID, ALICE, 1_000),
+			Ok(BridgeInOutcome::Credited(1_000))
+		);
+		assert_eq!(LimitedHandler::prepare_token_bridge_out([2u8; 32], ALICE, 1_000), Ok(1_000));
+		assert_eq!(BridgeLimits::remaining_allowance(BridgeId::get(), &ALICE, [2u8; 32]), None);
+
+		// each bridge has its own usage
+		assert_eq!(
+			BridgeLimits::remaining_allowance(OtherBridgeId::get(), &ALICE, RESOURCE_ID),
+			Some(500)
+		);
+		assert_eq!(OtherLimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 500), Ok(500));
+		assert_noop!(
+			OtherLimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 1),
+			Error::<Test>::LimitExceeded
+		);
+	})
+}
+
+#[test]
+fn limits_reset_every_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeLimits::set_period_limit(
+			RuntimeOrigin::root(),
+			RESOURCE_ID,
+			0,
+			Some(100)
+		));
+		assert_ok!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 100));
+		assert_noop!(
+			LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 1),
+			Error::<Test>::LimitExceeded
+		);
+
+		// the period is 10 blocks long
+		System::set_block_number(10);
+		assert_eq!(
+			BridgeLimits::remaining_allowance(BridgeId::get(), &ALICE, RESOURCE_ID),
+			Some(100)
+		);
+		assert_ok!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 40));
+		assert_eq!(
+			BridgeLimits::period_usage((BridgeId::get(), ALICE, RESOURCE_ID)),
+			PeriodUsage { period: 1, amount: 40 }
+		);
+	})
+}
+
+#[test]
+fn handler_weight_includes_checks() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_in_weight(RESOURCE_ID),
+			<() as WeightInfo>::check_bridge_in().saturating_add(Weight::from_parts(1, 0))
+		);
+		assert_eq!(
+			LimitedHandler::prepare_token_bridge_out_weight(RESOURCE_ID),
+			<() as WeightInfo>::check_bridge_out().saturating_add(Weight::from_parts(2, 0))
+		);
+	})
+}
+
+#[test]
+fn stale_usages_are_pruned_when_idle() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeLimits::set_period_limit(
+			RuntimeOrigin::root(),
+			RESOURCE_ID,
+			0,
+			Some(100)
+		));
+		assert_ok!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 10));
+		assert_ok!(LimitedHandler::prepare_token_bridge_out(RESOURCE_ID, BOB, 10));
+		System::set_block_number(10);
+		assert_ok!(OtherLimitedHandler::prepare_token_bridge_out(RESOURCE_ID, ALICE, 10));
+		assert_eq!(PeriodUsages::<Test>::iter().count(), 3);
+
+		// not even the cursor fits
+		assert_eq!(BridgeLimits::on_idle(10, Weight::zero()), Weight::zero());
+
+		// one usage per block, resuming where the last scan stopped
+		let budget = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
+		assert_eq!(BridgeLimits::on_idle(10, budget), budget);
+		assert!(UsageScanCursor::<Test>::get().is_some());
+		for _ in 0..3 {
+			BridgeLimits::on_idle(10, budget);
+		}
+		assert_eq!(UsageScanCursor::<Test>::get(), None);
+		assert_eq!(
+			PeriodUsages::<Test>::iter().collect::<Vec<_>>(),
+			vec![(
+				(OtherBridgeId::get(), ALICE, RESOURCE_ID),
+				PeriodUsage { period: 1, amount: 10 }
+			)]
+		);
+	})
+}
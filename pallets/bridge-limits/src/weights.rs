@@ -0,0 +1,146 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for pallet_bridge_limits
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI YET: the storage accesses follow the code and the times
+//! are estimates. Regenerate this file on reference hardware before relying on it, with
+//! `./scripts/benchmark-weights.sh`, which runs:
+
+// Command:
+// ./target/release/canbus
+// benchmark
+// pallet
+// --chain=dev
+// --wasm-execution=compiled
+// --pallet=pallet_bridge_limits
+// --extrinsic=*
+// --heap-pages=4096
+// --steps=50
+// --repeat=20
+// --header=./.maintain/header.txt
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/bridge-limits/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_bridge_limits.
+pub trait WeightInfo {
+	fn set_allowlist_enabled() -> Weight;
+	fn set_allowlisted() -> Weight;
+	fn set_blocked() -> Weight;
+	fn set_kyc_tier() -> Weight;
+	fn set_period_limit() -> Weight;
+	fn check_bridge_out() -> Weight;
+	fn check_bridge_in() -> Weight;
+}
+
+/// Weights for pallet_bridge_limits using the Litentry node and recommended hardware.
+pub struct LitentryWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
+	// Storage: BridgeLimits AllowlistEnabled (r:0 w:1)
+	fn set_allowlist_enabled() -> Weight {
+		Weight::from_parts(10_912_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Allowlist (r:0 w:1)
+	fn set_allowlisted() -> Weight {
+		Weight::from_parts(12_485_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Blocklist (r:0 w:1)
+	fn set_blocked() -> Weight {
+		Weight::from_parts(12_301_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits KycTiers (r:0 w:1)
+	fn set_kyc_tier() -> Weight {
+		Weight::from_parts(12_037_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits PeriodLimits (r:0 w:1)
+	fn set_period_limit() -> Weight {
+		Weight::from_parts(11_864_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Blocklist (r:1 w:0)
+	// Storage: BridgeLimits AllowlistEnabled (r:1 w:0)
+	// Storage: BridgeLimits Allowlist (r:1 w:0)
+	// Storage: BridgeLimits KycTiers (r:1 w:0)
+	// Storage: BridgeLimits PeriodLimits (r:1 w:0)
+	// Storage: BridgeLimits PeriodUsages (r:1 w:1)
+	fn check_bridge_out() -> Weight {
+		Weight::from_parts(15_742_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Blocklist (r:1 w:0)
+	fn check_bridge_in() -> Weight {
+		Weight::from_parts(7_215_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: BridgeLimits AllowlistEnabled (r:0 w:1)
+	fn set_allowlist_enabled() -> Weight {
+		Weight::from_parts(10_912_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Allowlist (r:0 w:1)
+	fn set_allowlisted() -> Weight {
+		Weight::from_parts(12_485_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Blocklist (r:0 w:1)
+	fn set_blocked() -> Weight {
+		Weight::from_parts(12_301_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits KycTiers (r:0 w:1)
+	fn set_kyc_tier() -> Weight {
+		Weight::from_parts(12_037_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits PeriodLimits (r:0 w:1)
+	fn set_period_limit() -> Weight {
+		Weight::from_parts(11_864_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Blocklist (r:1 w:0)
+	// Storage: BridgeLimits AllowlistEnabled (r:1 w:0)
+	// Storage: BridgeLimits Allowlist (r:1 w:0)
+	// Storage: BridgeLimits KycTiers (r:1 w:0)
+	// Storage: BridgeLimits PeriodLimits (r:1 w:0)
+	// Storage: BridgeLimits PeriodUsages (r:1 w:1)
+	fn check_bridge_out() -> Weight {
+		Weight::from_parts(15_742_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BridgeLimits Blocklist (r:1 w:0)
+	fn check_bridge_in() -> Weight {
+		Weight::from_parts(7_215_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+}
@@ -22,50 +22,124 @@
 #![allow(unused)]
 #![allow(clippy::useless_vec)]
 use super::*;
-use crate::Pallet as bridge_transfer;
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::{ensure, traits::SortedMembers, PalletId};
+use crate::{recipient::RecipientFormat, Pallet as bridge_transfer};
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
+use frame_support::{ensure, traits::SortedMembers, BoundedVec};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use pallet_bridge::{EnsureOrigin, Get};
 use sp_arithmetic::traits::Saturating;
 use sp_runtime::traits::AccountIdConversion;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 const MAXIMUM_ISSURANCE: u32 = 20_000;
 const NATIVE_TOKEN_RESOURCE_ID: [u8; 32] =
 	hex!("0000000000000000000000000000000a21dfe87028f214dd976be8479f5af001");
-fn create_user<T: Config>(string: &'static str, n: u32, seed: u32) -> T::AccountId {
-	let user: T::AccountId = account(string, n, seed);
-	bridge_transfer::<T>::transfer(
-		RawOrigin::Root.into(),
-		user.clone(),
-		(n * MAXIMUM_ISSURANCE).into(),
-		NATIVE_TOKEN_RESOURCE_ID,
-	);
+const DEST_CHAIN: BridgeChainId = 0;
 
+/// Creates a bridge member holding tokens of the whitelisted resource to bridge out.
+fn create_user<T: Config<I>, I: 'static>(string: &'static str, n: u32, seed: u32) -> T::AccountId {
+	let user: T::AccountId = account(string, n, seed);
+	setup_account::<T, I>(&user);
 	user
 }
 
-benchmarks! {
+fn setup_account<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	if !pallet_bridge::Pallet::<T, I>::chain_whitelisted(DEST_CHAIN) {
+		let origin =
+			<T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+				.expect("committee origin is available in benchmarks");
+		pallet_bridge::Pallet::<T, I>::whitelist_chain(origin, DEST_CHAIN)
+			.expect("chain isn't whitelisted yet");
+	}
+	if !T::TransferNativeMembers::contains(who) {
+		T::TransferNativeMembers::add(who);
+	}
+	T::BenchmarkHelper::setup_resource(NATIVE_TOKEN_RESOURCE_ID, who, MAXIMUM_ISSURANCE.into());
+}
+
+benchmarks_instance_pallet! {
 	transfer_assets{
-		let sender:T::AccountId = create_user::<T>("sender",0u32,1u32);
+		let sender:T::AccountId = create_user::<T, I>("sender",0u32,1u32);
 
 		ensure!(T::TransferNativeMembers::contains(&sender),"add transfer_native_member failed");
-		let dest_chain = 0;
+		let dest_chain = DEST_CHAIN;
+
+		let r_id = NATIVE_TOKEN_RESOURCE_ID;
+
+	}:_(RawOrigin::Signed(sender),50u32.into(),vec![0u8, 0u8, 0u8, 0u8].try_into().unwrap(),dest_chain,r_id)
+
+	transfer_assets_batch{
+		let n in 1 .. T::MaxBatchTransfers::get();
+
+		let sender:T::AccountId = create_user::<T, I>("sender",1u32,1u32);
+		let dest_chain = DEST_CHAIN;
+		let r_id = NATIVE_TOKEN_RESOURCE_ID;
+
+		let transfers: Vec<_> = (0..n).map(|_| (50u32.into(), vec![0u8, 0u8, 0u8, 0u8].try_into().unwrap())).collect();
+		let transfers: BoundedVec<_, T::MaxBatchTransfers> = transfers.try_into().unwrap();
+
+	}:_(RawOrigin::Signed(sender),transfers,dest_chain,r_id)
+
+	transfer_assets_with_call{
+		let n in 0 .. T::MaxPayloadSize::get();
 
+		let sender:T::AccountId = create_user::<T, I>("sender",1u32,1u32);
+		let dest_chain = DEST_CHAIN;
 		let r_id = NATIVE_TOKEN_RESOURCE_ID;
 
-	}:_(RawOrigin::Signed(sender),50u32.into(),vec![0u8, 0u8, 0u8, 0u8],dest_chain,r_id)
+		PayloadSizeLimits::<T, I>::insert(dest_chain, T::MaxPayloadSize::get());
+		let payload: BoundedVec<u8, T::MaxPayloadSize> = vec![1u8; n as usize].try_into().unwrap();
+
+	}:_(RawOrigin::Signed(sender),50u32.into(),vec![0u8, 0u8, 0u8, 0u8].try_into().unwrap(),payload,dest_chain,r_id)
+
+	set_payload_size_limit{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+	}:_<T::RuntimeOrigin>(origin, 0, Some(1024))
+	verify{
+		assert_eq!(PayloadSizeLimits::<T, I>::get(0), Some(1024));
+	}
 
 	transfer{
 		let r_id = NATIVE_TOKEN_RESOURCE_ID;
 
-		let sender = PalletId(*b"litry/bg").into_account_truncating();
+		let sender = pallet_bridge::Pallet::<T, I>::account_id();
 
-		let to_account:T::AccountId = create_user::<T>("to",1u32,2u32);
+		let to_account:T::AccountId = create_user::<T, I>("to",1u32,2u32);
 
 	}:_(RawOrigin::Signed(sender),to_account,50u32.into(), r_id)
+
+	transfer_to_evm{
+		let r_id = NATIVE_TOKEN_RESOURCE_ID;
+
+		let sender = pallet_bridge::Pallet::<T, I>::account_id();
+
+		let to = sp_core::H160::repeat_byte(1);
+		setup_account::<T, I>(&T::AddressMapping::into_account_id(to));
+
+	}:_(RawOrigin::Signed(sender),to,50u32.into(), r_id)
+
+	// the call fails without an EVM, which also covers the fallback mint
+	transfer_and_call_evm{
+		let r_id = NATIVE_TOKEN_RESOURCE_ID;
+
+		let sender = pallet_bridge::Pallet::<T, I>::account_id();
+
+		let target = sp_core::H160::repeat_byte(1);
+		let input: BoundedVec<u8, T::MaxPayloadSize> = vec![1u8; 4].try_into().unwrap();
+		let fallback: T::AccountId = create_user::<T, I>("fallback",1u32,2u32);
+
+	}:_(RawOrigin::Signed(sender),target,50u32.into(), r_id, input, 0, fallback)
+
+	set_recipient_format{
+		let origin = <T as pallet_bridge::Config<I>>::BridgeCommitteeOrigin::try_successful_origin()
+			.map_err(|_| "BadOrigin")?;
+		let format = Some(RecipientFormat::Substrate { ss58_prefix: Some(42) });
+	}:_<T::RuntimeOrigin>(origin, 0, format.clone())
+	verify{
+		assert_eq!(RecipientFormats::<T, I>::get(0), format);
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -23,6 +23,7 @@ pub use pallet::*;
 mod benchmarking;
 #[cfg(test)]
 mod mock;
+pub mod recipient;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -30,32 +31,37 @@ pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::weights::WeightInfo;
+	use crate::{recipient::RecipientFormat, weights::WeightInfo};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{SortedMembers, StorageVersion},
 		transactional,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::BadOrigin;
-	use sp_std::vec::Vec;
+	use sp_core::{H160, H256, U256};
+	use sp_runtime::traits::{BadOrigin, SaturatedConversion};
+	use sp_std::{vec, vec::Vec};
 
 	pub use pallet_bridge as bridge;
+	pub use pallet_evm::{AddressMapping, Log};
 
 	pub type ResourceId = bridge::ResourceId;
 	pub type BridgeChainId = bridge::BridgeChainId;
 
-	pub type BalanceOf<T> = <T as bridge::Config>::Balance;
+	pub type BalanceOf<T, I = ()> = <T as bridge::Config<I>>::Balance;
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + bridge::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config + bridge::Config<I> {
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// Specifies the origin check provided by the bridge for calls that can only be called by
 		/// the bridge pallet
 		type BridgeOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
@@ -64,58 +70,494 @@ pub mod pallet {
 		type TransferNativeMembers: SortedMembers<Self::AccountId>;
 
 		// Handler of asset transfer/burn/mint etc.
-		type BridgeHandler: BridgeHandler<BalanceOf<Self>, Self::AccountId, ResourceId>;
+		type BridgeHandler: BridgeHandler<BalanceOf<Self, I>, Self::AccountId, ResourceId>;
+
+		/// Maps EVM recipients of bridge-ins to their substrate account
+		type AddressMapping: AddressMapping<Self::AccountId>;
+
+		/// Address the Ethereum logs of bridge-ins to EVM recipients are emitted from
+		#[pallet::constant]
+		type EvmLogAddress: Get<H160>;
+
+		/// Receives the Ethereum logs of bridge-ins to EVM recipients
+		type EvmLogHandler: EvmLogHandler;
+
+		/// Maximum length of the recipient of a bridge-out, before it is checked against the
+		/// format of the destination chain
+		#[pallet::constant]
+		type MaxRecipientLength: Get<u32>;
+
+		/// Maximum number of recipients of `transfer_assets_batch`
+		#[pallet::constant]
+		type MaxBatchTransfers: Get<u32>;
+
+		/// Upper bound of the payload of `transfer_assets_with_call`, per-chain limits can only
+		/// be lower. Also bounds the calldata of `transfer_and_call_evm`.
+		#[pallet::constant]
+		type MaxPayloadSize: Get<u32>;
+
+		/// Executes the EVM calls of bridge-ins to contracts
+		type EvmCaller: EvmCaller;
+
+		/// Sets up the `BridgeHandler` for the benchmarks of this pallet
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, BalanceOf<Self, I>>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Recipient format of a destination chain changed, `None` disables validation
+		RecipientFormatSet { dest_id: BridgeChainId, format: Option<RecipientFormat> },
+		/// Bridged-in tokens were credited to the account mapped from an EVM address
+		TransferredToEvm {
+			resource_id: ResourceId,
+			recipient: H160,
+			who: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// Payload size limit of a destination chain changed, `None` disables payloads
+		PayloadSizeLimitSet { dest_id: BridgeChainId, limit: Option<u32> },
+		/// Bridged-in tokens were delivered to a contract which was called successfully
+		EvmCallExecuted {
+			resource_id: ResourceId,
+			target: H160,
+			amount: BalanceOf<T, I>,
+			used_gas: u64,
+		},
+		/// The contract call of a bridge-in failed, the tokens went to the fallback recipient
+		EvmCallFailed {
+			resource_id: ResourceId,
+			target: H160,
+			fallback: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// The bridge-in to a contract was time-locked, so the contract wasn't called. The
+		/// tokens are released to the fallback recipient.
+		EvmCallSkipped {
+			resource_id: ResourceId,
+			target: H160,
+			fallback: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Recipient doesn't match the address format of the destination chain
+		InvalidRecipient,
+		/// The destination chain doesn't accept payloads
+		PayloadNotSupported,
+		/// Payload exceeds the limit of the destination chain
+		PayloadTooLarge,
+		/// The contract call of a bridge-in failed
+		EvmCallFailed,
+		/// The bridge-in to a contract was time-locked, the contract can't be called
+		EvmCallQueued,
+	}
+
+	/// Address format of each destination chain. Recipients of chains without an entry are
+	/// not validated.
+	#[pallet::storage]
+	#[pallet::getter(fn recipient_format)]
+	pub type RecipientFormats<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BridgeChainId, RecipientFormat>;
+
+	/// Maximum payload size accepted by each destination chain. Chains without an entry don't
+	/// accept payloads.
+	#[pallet::storage]
+	#[pallet::getter(fn payload_size_limit)]
+	pub type PayloadSizeLimits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BridgeChainId, u32>;
+
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Transfers some amount of non-native token to some recipient on a (whitelisted)
 		/// destination chain.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::transfer_assets())]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::transfer_assets()
+				.saturating_add(T::BridgeHandler::prepare_token_bridge_out_weight(*resource_id))
+		)]
 		#[transactional]
 		pub fn transfer_assets(
 			origin: OriginFor<T>,
-			amount: BalanceOf<T>,
-			recipient: Vec<u8>,
+			amount: BalanceOf<T, I>,
+			recipient: BoundedVec<u8, T::MaxRecipientLength>,
 			dest_id: BridgeChainId,
 			resource_id: ResourceId,
 		) -> DispatchResult {
 			let source = ensure_signed(origin)?;
 			ensure!(T::TransferNativeMembers::contains(&source), BadOrigin);
-			let actual_dest_amount =
-				T::BridgeHandler::prepare_token_bridge_out(resource_id, source, amount)?;
-			<bridge::Pallet<T>>::signal_transfer_fungible(
-				dest_id,
-				resource_id,
-				recipient,
-				actual_dest_amount,
-			)
+			Self::do_transfer_assets(source, amount, recipient, dest_id, resource_id)
 		}
 
 		/// Executes a simple currency transfer using the bridge account as the source
 		/// Should only be called by bridge pallet
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::transfer())]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::transfer()
+				.saturating_add(T::BridgeHandler::prepare_token_bridge_in_weight(*rid))
+		)]
 		#[transactional]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: T::AccountId,
-			amount: BalanceOf<T>,
+			amount: BalanceOf<T, I>,
 			rid: ResourceId,
 		) -> DispatchResult {
 			T::BridgeOrigin::ensure_origin(origin)?;
 			T::BridgeHandler::prepare_token_bridge_in(rid, to, amount)?;
 			Ok(())
 		}
+
+		/// Sets the recipient address format of a destination chain, `None` removes it.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_recipient_format())]
+		pub fn set_recipient_format(
+			origin: OriginFor<T>,
+			dest_id: BridgeChainId,
+			format: Option<RecipientFormat>,
+		) -> DispatchResult {
+			<T as bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			RecipientFormats::<T, I>::set(dest_id, format.clone());
+			Self::deposit_event(Event::RecipientFormatSet { dest_id, format });
+			Ok(())
+		}
+
+		/// Executes a simple currency transfer to the account mapped from an EVM address,
+		/// using the bridge account as the source. A time-locked transfer emits neither the log
+		/// nor `TransferredToEvm`.
+		/// Should only be called by bridge pallet
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::transfer_to_evm()
+				.saturating_add(T::BridgeHandler::prepare_token_bridge_in_weight(*rid))
+				.saturating_add(T::EvmLogHandler::deposit_log_weight())
+		)]
+		#[transactional]
+		pub fn transfer_to_evm(
+			origin: OriginFor<T>,
+			to: H160,
+			amount: BalanceOf<T, I>,
+			rid: ResourceId,
+		) -> DispatchResult {
+			T::BridgeOrigin::ensure_origin(origin)?;
+			let who = T::AddressMapping::into_account_id(to);
+			let amount = match T::BridgeHandler::prepare_token_bridge_in(rid, who.clone(), amount)?
+			{
+				BridgeInOutcome::Credited(amount) => amount,
+				// nothing is credited until the release, which the handler reports itself
+				BridgeInOutcome::Queued(_) => return Ok(()),
+			};
+			T::EvmLogHandler::deposit_log(Self::bridge_in_log(rid, to, amount));
+			Self::deposit_event(Event::TransferredToEvm {
+				resource_id: rid,
+				recipient: to,
+				who,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Transfers some amount of non-native token to each of several recipients on a
+		/// (whitelisted) destination chain. Either all transfers succeed or none.
+		///
+		/// This is not cheaper than the single transfers: every item pays the bridge-out fee
+		/// and gets its own deposit nonce, since relayers and destination contracts handle one
+		/// recipient per deposit. The weight grows with the number of items accordingly.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::transfer_assets_batch(transfers.len() as u32)
+				.saturating_add(
+					T::BridgeHandler::prepare_token_bridge_out_weight(*resource_id)
+						.saturating_mul(transfers.len() as u64)
+				)
+		)]
+		#[transactional]
+		pub fn transfer_assets_batch(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<
+				(BalanceOf<T, I>, BoundedVec<u8, T::MaxRecipientLength>),
+				T::MaxBatchTransfers,
+			>,
+			dest_id: BridgeChainId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			ensure!(T::TransferNativeMembers::contains(&source), BadOrigin);
+			for (amount, recipient) in transfers {
+				Self::do_transfer_assets(source.clone(), amount, recipient, dest_id, resource_id)?;
+			}
+			Ok(())
+		}
+
+		/// Transfers some amount of non-native token to some recipient on a (whitelisted)
+		/// destination chain, along with a payload executed by the destination bridge handler.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::transfer_assets_with_call(payload.len() as u32)
+				.saturating_add(T::BridgeHandler::prepare_token_bridge_out_weight(*resource_id))
+		)]
+		#[transactional]
+		pub fn transfer_assets_with_call(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T, I>,
+			recipient: BoundedVec<u8, T::MaxRecipientLength>,
+			payload: BoundedVec<u8, T::MaxPayloadSize>,
+			dest_id: BridgeChainId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			ensure!(T::TransferNativeMembers::contains(&source), BadOrigin);
+			let limit =
+				Self::payload_size_limit(dest_id).ok_or(Error::<T, I>::PayloadNotSupported)?;
+			ensure!(payload.len() as u32 <= limit, Error::<T, I>::PayloadTooLarge);
+			let recipient = Self::ensure_valid_recipient(dest_id, &recipient)?;
+			let actual_dest_amount =
+				T::BridgeHandler::prepare_token_bridge_out(resource_id, source, amount)?;
+			<bridge::Pallet<T, I>>::signal_transfer_fungible_with_call(
+				dest_id,
+				resource_id,
+				recipient,
+				actual_dest_amount,
+				payload.into_inner(),
+			)
+		}
+
+		/// Sets the maximum payload size accepted by a destination chain, `None` disables
+		/// payloads.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_payload_size_limit())]
+		pub fn set_payload_size_limit(
+			origin: OriginFor<T>,
+			dest_id: BridgeChainId,
+			limit: Option<u32>,
+		) -> DispatchResult {
+			<T as bridge::Config<I>>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			PayloadSizeLimits::<T, I>::set(dest_id, limit);
+			Self::deposit_event(Event::PayloadSizeLimitSet { dest_id, limit });
+			Ok(())
+		}
+
+		/// Delivers bridged-in tokens to a contract and calls it with `input`, using the bridge
+		/// account as the source. If the call fails, the tokens go to `fallback` instead. If the
+		/// bridge-in is time-locked, the call is skipped and the release goes to `fallback`.
+		/// Should only be called by bridge pallet
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::transfer_and_call_evm()
+				.saturating_add(T::EvmCaller::gas_to_weight(*gas_limit))
+				// the fallback is paid after the reverted delivery
+				.saturating_add(
+					T::BridgeHandler::prepare_token_bridge_in_weight(*rid).saturating_mul(2)
+				)
+		)]
+		#[transactional]
+		pub fn transfer_and_call_evm(
+			origin: OriginFor<T>,
+			target: H160,
+			amount: BalanceOf<T, I>,
+			rid: ResourceId,
+			input: BoundedVec<u8, T::MaxPayloadSize>,
+			gas_limit: u64,
+			fallback: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::BridgeOrigin::ensure_origin(origin)?;
+
+			let mut used_gas = 0;
+			let delivered = frame_support::storage::with_storage_layer(|| {
+				let who = T::AddressMapping::into_account_id(target);
+				// the contract can't be called once the release is due, so the queued release
+				// is reverted and made to the fallback recipient
+				if let BridgeInOutcome::Queued(_) =
+					T::BridgeHandler::prepare_token_bridge_in(rid, who, amount)?
+				{
+					return Err(Error::<T, I>::EvmCallQueued.into())
+				}
+				let outcome = T::EvmCaller::call(
+					T::EvmLogAddress::get(),
+					target,
+					input.into_inner(),
+					gas_limit,
+				);
+				used_gas = outcome.used_gas;
+				// reverts the mint to the contract
+				ensure!(outcome.succeeded, Error::<T, I>::EvmCallFailed);
+				Ok::<_, DispatchError>(())
+			});
+
+			let mut bridge_in_weight = T::BridgeHandler::prepare_token_bridge_in_weight(rid);
+			match delivered {
+				Ok(()) => Self::deposit_event(Event::EvmCallExecuted {
+					resource_id: rid,
+					target,
+					amount,
+					used_gas,
+				}),
+				Err(e) => {
+					T::BridgeHandler::prepare_token_bridge_in(rid, fallback.clone(), amount)?;
+					bridge_in_weight = bridge_in_weight.saturating_mul(2);
+					if e == Error::<T, I>::EvmCallQueued.into() {
+						Self::deposit_event(Event::EvmCallSkipped {
+							resource_id: rid,
+							target,
+							fallback,
+							amount,
+						});
+					} else {
+						Self::deposit_event(Event::EvmCallFailed {
+							resource_id: rid,
+							target,
+							fallback,
+							amount,
+						});
+					}
+				},
+			}
+			Ok(Some(
+				<T as Config<I>>::WeightInfo::transfer_and_call_evm()
+					.saturating_add(T::EvmCaller::gas_to_weight(used_gas))
+					.saturating_add(bridge_in_weight),
+			)
+			.into())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		fn do_transfer_assets(
+			source: T::AccountId,
+			amount: BalanceOf<T, I>,
+			recipient: BoundedVec<u8, T::MaxRecipientLength>,
+			dest_id: BridgeChainId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let recipient = Self::ensure_valid_recipient(dest_id, &recipient)?;
+			let actual_dest_amount =
+				T::BridgeHandler::prepare_token_bridge_out(resource_id, source, amount)?;
+			<bridge::Pallet<T, I>>::signal_transfer_fungible(
+				dest_id,
+				resource_id,
+				recipient,
+				actual_dest_amount,
+			)
+		}
+
+		/// Rejects recipients not matching the format registered for `dest_id`, otherwise
+		/// returns the recipient to forward to it.
+		pub fn ensure_valid_recipient(
+			dest_id: BridgeChainId,
+			recipient: &[u8],
+		) -> Result<Vec<u8>, DispatchError> {
+			match Self::recipient_format(dest_id) {
+				Some(format) => format
+					.normalize(recipient)
+					.ok_or_else(|| Error::<T, I>::InvalidRecipient.into()),
+				None => Ok(recipient.to_vec()),
+			}
+		}
+
+		/// Ethereum log of a bridge-in to `to`, i.e.
+		/// `BridgeIn(bytes32 indexed resourceId, address indexed recipient, uint256 amount)`.
+		pub fn bridge_in_log(rid: ResourceId, to: H160, amount: BalanceOf<T, I>) -> Log {
+			let signature = sp_io::hashing::keccak_256(b"BridgeIn(bytes32,address,uint256)");
+			let mut data = [0u8; 32];
+			U256::from(amount.saturated_into::<u128>()).to_big_endian(&mut data);
+			Log {
+				address: T::EvmLogAddress::get(),
+				topics: vec![H256(signature), H256(rid), to.into()],
+				data: data.to_vec(),
+			}
+		}
+	}
+
+	/// Outcome of an EVM call made by [`EvmCaller`].
+	pub struct EvmCallOutcome {
+		pub succeeded: bool,
+		pub used_gas: u64,
+	}
+
+	/// Executes EVM calls on behalf of the bridge.
+	pub trait EvmCaller {
+		fn call(source: H160, target: H160, input: Vec<u8>, gas_limit: u64) -> EvmCallOutcome;
+
+		fn gas_to_weight(gas: u64) -> Weight;
+	}
+
+	/// Without an EVM every call fails, so bridge-ins go to the fallback recipient.
+	impl EvmCaller for () {
+		fn call(_: H160, _: H160, _: Vec<u8>, _: u64) -> EvmCallOutcome {
+			EvmCallOutcome { succeeded: false, used_gas: 0 }
+		}
+
+		fn gas_to_weight(_gas: u64) -> Weight {
+			Weight::zero()
+		}
+	}
+
+	/// Sink for the Ethereum logs emitted by this pallet.
+	///
+	/// The logs aren't backed by an Ethereum transaction of the bridge-in itself. To be returned
+	/// by `eth_getLogs`, a sink has to put them in a transaction receipt, e.g. by applying a
+	/// transaction that emits them.
+	pub trait EvmLogHandler {
+		fn deposit_log(log: Log);
+
+		fn deposit_log_weight() -> Weight;
+	}
+
+	impl EvmLogHandler for () {
+		fn deposit_log(_log: Log) {}
+
+		fn deposit_log_weight() -> Weight {
+			Weight::zero()
+		}
+	}
+
+	/// What a bridge-in did with the bridged amount.
+	#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+	pub enum BridgeInOutcome<B> {
+		/// Credited to the recipient, after fees
+		Credited(B),
+		/// Time-locked, the recipient is credited once it is released
+		Queued(B),
 	}
 
 	pub trait BridgeHandler<B, A, R> {
-		fn prepare_token_bridge_in(resource_id: R, who: A, amount: B) -> Result<B, DispatchError>;
+		fn prepare_token_bridge_in(
+			resource_id: R,
+			who: A,
+			amount: B,
+		) -> Result<BridgeInOutcome<B>, DispatchError>;
+		// Like `prepare_token_bridge_in`, but always time-locks the bridge-in frozen, to be
+		// thawed or cancelled by a guardian. Returns the queued amount
+		fn quarantine_token_bridge_in(
+			resource_id: R,
+			who: A,
+			amount: B,
+		) -> Result<B, DispatchError>;
 		// Return actual amount to target chain after deduction e.g fee
 		fn prepare_token_bridge_out(resource_id: R, who: A, amount: B) -> Result<B, DispatchError>;
+		// Weight of `prepare_token_bridge_in` for the resource, also covering
+		// `quarantine_token_bridge_in`. Not included in this pallet's weights
+		fn prepare_token_bridge_in_weight(resource_id: R) -> Weight;
+		// Weight of `prepare_token_bridge_out` for the resource
+		fn prepare_token_bridge_out_weight(resource_id: R) -> Weight;
+	}
+
+	/// Sets up the `BridgeHandler` for the benchmarks of this pallet.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<A, B> {
+		/// Makes `resource_id` bridgeable both ways and gives `who` `amount` to bridge out, on
+		/// top of what keeps the account alive.
+		fn setup_resource(resource_id: ResourceId, who: &A, amount: B);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<A, B> BenchmarkHelper<A, B> for () {
+		fn setup_resource(_: ResourceId, _: &A, _: B) {}
 	}
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{self as bridge_transfer, Config};
+use crate::{self as bridge_transfer, BridgeInOutcome, Config};
 use frame_support::{
 	assert_ok, derive_impl, ord_parameter_types, parameter_types,
 	traits::{
@@ -22,13 +22,14 @@ use frame_support::{
 		tokens::{Fortitude, Precision},
 		ConstU32, ConstU64, SortedMembers,
 	},
+	weights::Weight,
 	PalletId,
 };
 use frame_system as system;
 use hex_literal::hex;
 pub use pallet_balances as balances;
 use pallet_bridge::{self as bridge, ResourceId};
-use sp_core::{ConstU16, H256};
+use sp_core::{ConstU16, H160, H256};
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 	BuildStorage, DispatchError,
@@ -101,6 +102,7 @@ parameter_types! {
 	pub const TestChainId: u8 = 5;
 	pub const ProposalLifetime: u64 = 50;
 	pub const TreasuryAccount:u64 = 0x8;
+	pub const BridgePalletId: PalletId = PalletId(*b"litry/bg");
 }
 
 impl bridge::Config for Test {
@@ -108,8 +110,10 @@ impl bridge::Config for Test {
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Proposal = RuntimeCall;
 	type BridgeChainId = TestChainId;
+	type BridgePalletId = BridgePalletId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxRelayers = ConstU32<16>;
 	type WeightInfo = ();
 }
 
@@ -142,14 +146,75 @@ impl SortedMembers<u64> for MembersProvider {
 	}
 }
 
+parameter_types! {
+	pub EvmLogAddress: H160 = H160::from_low_u64_be(20482);
+	pub static EvmLogs: Vec<bridge_transfer::Log> = vec![];
+}
+
+/// Maps EVM addresses to their low 8 bytes
+pub struct MockAddressMapping;
+impl bridge_transfer::AddressMapping<AccountId> for MockAddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		address.to_low_u64_be()
+	}
+}
+
+pub struct MockEvmLogHandler;
+impl bridge_transfer::EvmLogHandler for MockEvmLogHandler {
+	fn deposit_log(log: bridge_transfer::Log) {
+		EvmLogs::mutate(|logs| logs.push(log));
+	}
+
+	fn deposit_log_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Calls succeed if the input starts with `0x01`
+pub struct MockEvmCaller;
+impl bridge_transfer::EvmCaller for MockEvmCaller {
+	fn call(
+		_source: H160,
+		_target: H160,
+		input: Vec<u8>,
+		gas_limit: u64,
+	) -> bridge_transfer::EvmCallOutcome {
+		bridge_transfer::EvmCallOutcome {
+			succeeded: input.first() == Some(&1),
+			used_gas: gas_limit / 2,
+		}
+	}
+
+	fn gas_to_weight(gas: u64) -> Weight {
+		Weight::from_parts(gas, 0)
+	}
+}
+
+parameter_types! {
+	// bridge-ins above this amount are time-locked
+	pub static QueueAbove: Balance = Balance::MAX;
+}
+
 pub struct MockAssetsHandler;
 impl bridge_transfer::BridgeHandler<Balance, AccountId, ResourceId> for MockAssetsHandler {
 	fn prepare_token_bridge_in(
 		_: ResourceId,
 		who: AccountId,
 		amount: Balance,
-	) -> Result<Balance, DispatchError> {
+	) -> Result<BridgeInOutcome<Balance>, DispatchError> {
+		if amount > QueueAbove::get() {
+			return Ok(BridgeInOutcome::Queued(amount))
+		}
 		<Balances as fungible::Mutate<AccountId>>::mint_into(&who, amount)
+			.map(BridgeInOutcome::Credited)
+	}
+
+	fn quarantine_token_bridge_in(
+		_: ResourceId,
+		_: AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(amount)
 	}
 	// Return actual amount to target chain after deduction e.g fee
 	fn prepare_token_bridge_out(
@@ -164,12 +229,39 @@ impl bridge_transfer::BridgeHandler<Balance, AccountId, ResourceId> for MockAsse
 			Fortitude::Polite,
 		)
 	}
+
+	fn prepare_token_bridge_in_weight(_: ResourceId) -> Weight {
+		Weight::zero()
+	}
+
+	fn prepare_token_bridge_out_weight(_: ResourceId) -> Weight {
+		Weight::zero()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl bridge_transfer::BenchmarkHelper<AccountId, Balance> for MockBenchmarkHelper {
+	fn setup_resource(_: ResourceId, who: &AccountId, amount: Balance) {
+		assert_ok!(<Balances as fungible::Mutate<AccountId>>::mint_into(who, amount + 1));
+	}
 }
 
 impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgeOrigin = bridge::EnsureBridge<Test>;
 	type TransferNativeMembers = MembersProvider;
 	type BridgeHandler = MockAssetsHandler;
+	type AddressMapping = MockAddressMapping;
+	type EvmLogAddress = EvmLogAddress;
+	type EvmLogHandler = MockEvmLogHandler;
+	type MaxRecipientLength = ConstU32<64>;
+	type MaxBatchTransfers = ConstU32<16>;
+	type MaxPayloadSize = ConstU32<64>;
+	type EvmCaller = MockEvmCaller;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
 	type WeightInfo = ();
 }
 
@@ -179,7 +271,7 @@ pub const RELAYER_C: u64 = 0x4;
 pub const ENDOWED_BALANCE: u64 = 100_000_000;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let bridge_id = PalletId(*b"litry/bg").into_account_truncating();
+	let bridge_id = BridgePalletId::get().into_account_truncating();
 	let dest_chain = 0u8;
 	let treasury_account: u64 = 0x8;
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
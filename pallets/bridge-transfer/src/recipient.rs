@@ -0,0 +1,120 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Recipient address formats of destination chains.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const SS58_PREFIX: &[u8] = b"SS58PRE";
+/// Length of the longest SS58 encoded 32-byte account, i.e. with a two-byte network prefix
+pub const MAX_SS58_LEN: usize = 50;
+
+/// Address format expected by a destination chain.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum RecipientFormat {
+	/// 20-byte EVM address
+	#[codec(index = 0)]
+	Evm,
+	/// 32-byte Substrate account. With a prefix set, the SS58 encoded address of that network
+	/// is accepted as well and forwarded as the 32-byte account.
+	#[codec(index = 1)]
+	Substrate { ss58_prefix: Option<u16> },
+	/// Non-empty bytes of at most `max_len`
+	#[codec(index = 2)]
+	Bytes { max_len: u32 },
+}
+
+impl RecipientFormat {
+	/// Checks whether `recipient` is a well-formed address of this format.
+	pub fn validate(&self, recipient: &[u8]) -> bool {
+		self.normalize(recipient).is_some()
+	}
+
+	/// Returns the address to forward to the destination chain, `None` if `recipient` isn't a
+	/// well-formed address of this format.
+	pub fn normalize(&self, recipient: &[u8]) -> Option<Vec<u8>> {
+		let valid = match self {
+			Self::Evm => recipient.len() == 20,
+			Self::Substrate { ss58_prefix: None } => recipient.len() == 32,
+			Self::Substrate { ss58_prefix: Some(_) } if recipient.len() == 32 => true,
+			Self::Substrate { ss58_prefix: Some(prefix) } => {
+				let (ident, account) = ss58_decode(recipient)?;
+				return (ident == *prefix).then(|| account.to_vec())
+			},
+			Self::Bytes { max_len } =>
+				!recipient.is_empty() && recipient.len() <= *max_len as usize,
+		};
+		valid.then(|| recipient.to_vec())
+	}
+}
+
+/// Decodes an SS58 encoded 32-byte account into its network prefix and account bytes,
+/// verifying the checksum.
+pub fn ss58_decode(encoded: &[u8]) -> Option<(u16, [u8; 32])> {
+	// base58 decoding is quadratic in the input length
+	if encoded.len() > MAX_SS58_LEN {
+		return None
+	}
+	let data = base58_decode(encoded)?;
+	let (prefix_len, ident) = match data.first()? {
+		0..=63 => (1, data[0] as u16),
+		64..=127 => {
+			let lower = (data[0] << 2) | (*data.get(1)? >> 6);
+			let upper = data[1] & 0b0011_1111;
+			(2, (lower as u16) | ((upper as u16) << 8))
+		},
+		_ => return None,
+	};
+	if data.len() != prefix_len + 32 + 2 {
+		return None
+	}
+
+	let body = &data[..prefix_len + 32];
+	let hash = sp_core::hashing::blake2_512(&[SS58_PREFIX, body].concat());
+	if data[prefix_len + 32..] != hash[..2] {
+		return None
+	}
+
+	let mut account = [0u8; 32];
+	account.copy_from_slice(&body[prefix_len..]);
+	Some((ident, account))
+}
+
+/// Decodes a base58 (bitcoin alphabet) string.
+fn base58_decode(encoded: &[u8]) -> Option<Vec<u8>> {
+	// little endian while decoding
+	let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+	for c in encoded {
+		let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+		for byte in bytes.iter_mut() {
+			carry += (*byte as u32) * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+			carry >>= 8;
+		}
+	}
+	// each leading '1' is a leading zero byte
+	bytes.extend(encoded.iter().take_while(|c| **c == b'1').map(|_| 0));
+	bytes.reverse();
+	Some(bytes)
+}
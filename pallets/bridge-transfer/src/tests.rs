@@ -17,14 +17,16 @@
 use super::{
 	bridge,
 	mock::{
-		assert_events, balances, new_test_ext, Balances, Bridge, BridgeTransfer,
-		NativeTokenResourceId, ProposalLifetime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Test,
-		TreasuryAccount, ENDOWED_BALANCE, RELAYER_A, RELAYER_B, RELAYER_C,
+		assert_events, balances, new_test_ext, Balances, Bridge, BridgeTransfer, EvmLogAddress,
+		EvmLogs, NativeTokenResourceId, ProposalLifetime, QueueAbove, RuntimeCall, RuntimeEvent,
+		RuntimeOrigin, System, Test, TreasuryAccount, ENDOWED_BALANCE, RELAYER_A, RELAYER_B,
+		RELAYER_C,
 	},
 	*,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 use hex_literal::hex;
+use sp_core::{H160, H256};
 use sp_runtime::ArithmeticError;
 
 fn make_transfer_proposal(to: u64, amount: u64) -> RuntimeCall {
@@ -72,7 +74,7 @@ fn transfer_assets() {
 		assert_ok!(Pallet::<Test>::transfer_assets(
 			RuntimeOrigin::signed(RELAYER_A),
 			100,
-			dest_account.clone(),
+			dest_account.clone().try_into().unwrap(),
 			dest_bridge_id,
 			resource_id
 		));
@@ -186,3 +188,474 @@ fn create_successful_transfer_proposal() {
 		]);
 	})
 }
+
+#[test]
+fn ss58_decode_works() {
+	let alice = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+	assert_eq!(
+		recipient::ss58_decode(b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+		Some((42, alice))
+	);
+	assert_eq!(
+		recipient::ss58_decode(b"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"),
+		Some((0, alice))
+	);
+	// bad checksum
+	assert_eq!(recipient::ss58_decode(b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"), None);
+	// not base58
+	assert_eq!(recipient::ss58_decode(b"0GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"), None);
+	// longer than any SS58 encoded account, rejected before decoding
+	assert_eq!(recipient::ss58_decode(&[b'z'; recipient::MAX_SS58_LEN + 1]), None);
+}
+
+#[test]
+fn recipient_format_validation() {
+	use recipient::RecipientFormat;
+
+	assert!(RecipientFormat::Evm.validate(&[1u8; 20]));
+	assert!(!RecipientFormat::Evm.validate(&[1u8; 32]));
+	assert!(RecipientFormat::Substrate { ss58_prefix: None }.validate(&[1u8; 32]));
+	assert!(!RecipientFormat::Substrate { ss58_prefix: None }.validate(&[1u8; 20]));
+	let substrate = RecipientFormat::Substrate { ss58_prefix: Some(42) };
+	assert!(substrate.validate(b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"));
+	assert!(!substrate.validate(b"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"));
+	assert!(substrate.validate(&[1u8; 32]));
+	assert!(!substrate.validate(&[1u8; 33]));
+	assert_eq!(
+		substrate.normalize(b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+		Some(hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d").to_vec())
+	);
+	assert_eq!(substrate.normalize(&[1u8; 32]), Some(vec![1u8; 32]));
+	assert!(RecipientFormat::Bytes { max_len: 4 }.validate(&[1u8; 4]));
+	assert!(!RecipientFormat::Bytes { max_len: 4 }.validate(&[1u8; 5]));
+	assert!(!RecipientFormat::Bytes { max_len: 4 }.validate(&[]));
+}
+
+#[test]
+fn transfer_assets_rejects_malformed_recipient() {
+	let dest_bridge_id: bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeTransfer::set_recipient_format(
+				RuntimeOrigin::signed(RELAYER_A),
+				dest_bridge_id,
+				Some(recipient::RecipientFormat::Evm)
+			),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(BridgeTransfer::set_recipient_format(
+			RuntimeOrigin::root(),
+			dest_bridge_id,
+			Some(recipient::RecipientFormat::Evm)
+		));
+		assert_events(vec![RuntimeEvent::BridgeTransfer(Event::RecipientFormatSet {
+			dest_id: dest_bridge_id,
+			format: Some(recipient::RecipientFormat::Evm),
+		})]);
+
+		assert_noop!(
+			BridgeTransfer::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1; 32].try_into().unwrap(),
+				dest_bridge_id,
+				resource_id
+			),
+			Error::<Test>::InvalidRecipient
+		);
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+
+		assert_ok!(BridgeTransfer::transfer_assets(
+			RuntimeOrigin::signed(RELAYER_A),
+			100,
+			vec![1; 20].try_into().unwrap(),
+			dest_bridge_id,
+			resource_id
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE - 100);
+
+		// removing the format disables validation
+		assert_ok!(BridgeTransfer::set_recipient_format(
+			RuntimeOrigin::root(),
+			dest_bridge_id,
+			None
+		));
+		assert_ok!(BridgeTransfer::transfer_assets(
+			RuntimeOrigin::signed(RELAYER_A),
+			100,
+			vec![1; 32].try_into().unwrap(),
+			dest_bridge_id,
+			resource_id
+		));
+	})
+}
+
+#[test]
+fn transfer_assets_forwards_decoded_ss58_recipient() {
+	let dest_bridge_id: bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let alice = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeTransfer::set_recipient_format(
+			RuntimeOrigin::root(),
+			dest_bridge_id,
+			Some(recipient::RecipientFormat::Substrate { ss58_prefix: Some(42) })
+		));
+		assert_ok!(BridgeTransfer::transfer_assets(
+			RuntimeOrigin::signed(RELAYER_A),
+			100,
+			b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_vec().try_into().unwrap(),
+			dest_bridge_id,
+			resource_id
+		));
+		assert_ok!(BridgeTransfer::transfer_assets(
+			RuntimeOrigin::signed(RELAYER_A),
+			100,
+			alice.to_vec().try_into().unwrap(),
+			dest_bridge_id,
+			resource_id
+		));
+		assert_eq!(
+			Bridge::bridge_events(),
+			vec![
+				bridge::BridgeEvent::FungibleTransfer(
+					dest_bridge_id,
+					1,
+					resource_id,
+					100,
+					alice.to_vec()
+				),
+				bridge::BridgeEvent::FungibleTransfer(
+					dest_bridge_id,
+					2,
+					resource_id,
+					100,
+					alice.to_vec()
+				),
+			]
+		);
+	})
+}
+
+#[test]
+fn transfer_to_evm() {
+	let resource_id = NativeTokenResourceId::get();
+	let to = H160::from_low_u64_be(RELAYER_A);
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeTransfer::transfer_to_evm(RuntimeOrigin::signed(RELAYER_A), to, 10, resource_id),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(BridgeTransfer::transfer_to_evm(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			to,
+			10,
+			resource_id,
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+
+		assert_events(vec![
+			RuntimeEvent::Balances(balances::Event::Minted { who: RELAYER_A, amount: 10 }),
+			RuntimeEvent::BridgeTransfer(Event::TransferredToEvm {
+				resource_id,
+				recipient: to,
+				who: RELAYER_A,
+				amount: 10,
+			}),
+		]);
+
+		let log = BridgeTransfer::bridge_in_log(resource_id, to, 10);
+		assert_eq!(log.address, EvmLogAddress::get());
+		assert_eq!(log.topics[1], H256(resource_id));
+		assert_eq!(log.topics[2], H256::from(to));
+		assert_eq!(log.data[31], 10);
+		assert_eq!(EvmLogs::get(), vec![log.clone()]);
+
+		// a time-locked bridge-in isn't credited yet
+		QueueAbove::set(10);
+		assert_ok!(BridgeTransfer::transfer_to_evm(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			to,
+			11,
+			resource_id,
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+		assert_eq!(EvmLogs::get(), vec![log]);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::BridgeTransfer(Event::TransferredToEvm { amount: 11, .. })
+		)));
+	})
+}
+
+#[test]
+fn transfer_and_call_evm() {
+	let resource_id = NativeTokenResourceId::get();
+	let target = H160::from_low_u64_be(RELAYER_A);
+	let fallback = RELAYER_B;
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeTransfer::transfer_and_call_evm(
+				RuntimeOrigin::signed(RELAYER_A),
+				target,
+				10,
+				resource_id,
+				vec![1].try_into().unwrap(),
+				100,
+				fallback,
+			),
+			sp_runtime::traits::BadOrigin
+		);
+
+		// the call succeeds, tokens stay with the contract
+		assert_ok!(BridgeTransfer::transfer_and_call_evm(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			target,
+			10,
+			resource_id,
+			vec![1, 2, 3].try_into().unwrap(),
+			100,
+			fallback,
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+		assert_events(vec![
+			RuntimeEvent::Balances(balances::Event::Minted { who: RELAYER_A, amount: 10 }),
+			RuntimeEvent::BridgeTransfer(Event::EvmCallExecuted {
+				resource_id,
+				target,
+				amount: 10,
+				used_gas: 50,
+			}),
+		]);
+
+		// the call reverts, tokens go to the fallback recipient
+		assert_ok!(BridgeTransfer::transfer_and_call_evm(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			target,
+			20,
+			resource_id,
+			vec![0].try_into().unwrap(),
+			100,
+			fallback,
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+		assert_eq!(Balances::free_balance(RELAYER_B), 20);
+		assert_events(vec![
+			RuntimeEvent::Balances(balances::Event::Minted { who: RELAYER_B, amount: 20 }),
+			RuntimeEvent::BridgeTransfer(Event::EvmCallFailed {
+				resource_id,
+				target,
+				fallback,
+				amount: 20,
+			}),
+		]);
+	})
+}
+
+#[test]
+fn transfer_and_call_evm_falls_back_when_call_reverts() {
+	let resource_id = NativeTokenResourceId::get();
+	let target = H160::from_low_u64_be(RELAYER_A);
+	let fallback = RELAYER_B;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeTransfer::transfer_and_call_evm(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			target,
+			30,
+			resource_id,
+			vec![0xff, 1].try_into().unwrap(),
+			100,
+			fallback,
+		));
+		// the mint to the contract was reverted together with the call
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+		assert_eq!(Balances::free_balance(RELAYER_B), 30);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Balances(balances::Event::Minted { who: RELAYER_A, .. })
+		)));
+		assert_events(vec![
+			RuntimeEvent::Balances(balances::Event::Minted { who: RELAYER_B, amount: 30 }),
+			RuntimeEvent::BridgeTransfer(Event::EvmCallFailed {
+				resource_id,
+				target,
+				fallback,
+				amount: 30,
+			}),
+		]);
+	})
+}
+
+#[test]
+fn transfer_and_call_evm_skips_call_when_queued() {
+	let resource_id = NativeTokenResourceId::get();
+	let target = H160::from_low_u64_be(RELAYER_A);
+	let fallback = RELAYER_B;
+
+	new_test_ext().execute_with(|| {
+		QueueAbove::set(10);
+		// the call would succeed, but the time-locked tokens can't be delivered yet
+		assert_ok!(BridgeTransfer::transfer_and_call_evm(
+			RuntimeOrigin::signed(Bridge::account_id()),
+			target,
+			11,
+			resource_id,
+			vec![1].try_into().unwrap(),
+			100,
+			fallback,
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+		assert_eq!(Balances::free_balance(RELAYER_B), 0);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::BridgeTransfer(Event::EvmCallExecuted { .. }) |
+				RuntimeEvent::BridgeTransfer(Event::EvmCallFailed { .. })
+		)));
+		assert_events(vec![RuntimeEvent::BridgeTransfer(Event::EvmCallSkipped {
+			resource_id,
+			target,
+			fallback,
+			amount: 11,
+		})]);
+	})
+}
+
+#[test]
+fn transfer_assets_batch() {
+	let dest_bridge_id: bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+
+	new_test_ext().execute_with(|| {
+		let transfers: BoundedVec<_, _> =
+			vec![(100, vec![1; 20].try_into().unwrap()), (200, vec![2; 20].try_into().unwrap())]
+				.try_into()
+				.unwrap();
+		assert_ok!(BridgeTransfer::transfer_assets_batch(
+			RuntimeOrigin::signed(RELAYER_A),
+			transfers,
+			dest_bridge_id,
+			resource_id
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE - 300);
+		assert_events(vec![
+			RuntimeEvent::Balances(balances::Event::Burned { who: RELAYER_A, amount: 100 }),
+			RuntimeEvent::Bridge(bridge::Event::FungibleTransfer(
+				dest_bridge_id,
+				1,
+				resource_id,
+				100,
+				vec![1; 20],
+			)),
+			RuntimeEvent::Balances(balances::Event::Burned { who: RELAYER_A, amount: 200 }),
+			RuntimeEvent::Bridge(bridge::Event::FungibleTransfer(
+				dest_bridge_id,
+				2,
+				resource_id,
+				200,
+				vec![2; 20],
+			)),
+		]);
+
+		// a single malformed recipient reverts the whole batch
+		assert_ok!(BridgeTransfer::set_recipient_format(
+			RuntimeOrigin::root(),
+			dest_bridge_id,
+			Some(recipient::RecipientFormat::Evm)
+		));
+		let transfers: BoundedVec<_, _> =
+			vec![(100, vec![1; 20].try_into().unwrap()), (200, vec![2; 32].try_into().unwrap())]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			BridgeTransfer::transfer_assets_batch(
+				RuntimeOrigin::signed(RELAYER_A),
+				transfers,
+				dest_bridge_id,
+				resource_id
+			),
+			Error::<Test>::InvalidRecipient
+		);
+	})
+}
+
+#[test]
+fn transfer_assets_with_call() {
+	let dest_bridge_id: bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let payload: BoundedVec<u8, _> = vec![0xab; 8].try_into().unwrap();
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeTransfer::transfer_assets_with_call(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1; 20].try_into().unwrap(),
+				payload.clone(),
+				dest_bridge_id,
+				resource_id
+			),
+			Error::<Test>::PayloadNotSupported
+		);
+
+		assert_ok!(BridgeTransfer::set_payload_size_limit(
+			RuntimeOrigin::root(),
+			dest_bridge_id,
+			Some(4)
+		));
+		assert_events(vec![RuntimeEvent::BridgeTransfer(Event::PayloadSizeLimitSet {
+			dest_id: dest_bridge_id,
+			limit: Some(4),
+		})]);
+		assert_noop!(
+			BridgeTransfer::transfer_assets_with_call(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1; 20].try_into().unwrap(),
+				payload.clone(),
+				dest_bridge_id,
+				resource_id
+			),
+			Error::<Test>::PayloadTooLarge
+		);
+
+		assert_ok!(BridgeTransfer::set_payload_size_limit(
+			RuntimeOrigin::root(),
+			dest_bridge_id,
+			Some(8)
+		));
+		assert_ok!(BridgeTransfer::transfer_assets_with_call(
+			RuntimeOrigin::signed(RELAYER_A),
+			100,
+			vec![1; 20].try_into().unwrap(),
+			payload.clone(),
+			dest_bridge_id,
+			resource_id
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE - 100);
+		assert_eq!(
+			Bridge::bridge_events(),
+			vec![bridge::BridgeEvent::FungibleTransferWithCall(
+				dest_bridge_id,
+				1,
+				resource_id,
+				100,
+				vec![1; 20],
+				payload.to_vec(),
+			)]
+		);
+		assert_events(vec![RuntimeEvent::Bridge(bridge::Event::FungibleTransferWithCall(
+			dest_bridge_id,
+			1,
+			resource_id,
+			100,
+			vec![1; 20],
+			payload.into_inner(),
+		))]);
+	})
+}
@@ -14,27 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_bridge_transfer
+//! Weights for pallet_bridge_transfer
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-09-29, STEPS: `20`, REPEAT: 50, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("litentry-dev"), DB CACHE: 20
+//! NOT GENERATED BY THE BENCHMARK CLI YET: the storage accesses follow the code and the times
+//! are estimates. Regenerate this file on reference hardware before relying on it, with
+//! `./scripts/benchmark-weights.sh`, which runs:
 
-// Executed Command:
-// ./target/release/litentry-collator
+// Command:
+// ./target/release/canbus
 // benchmark
 // pallet
-// --chain=litentry-dev
-// --execution=wasm
-// --db-cache=20
+// --chain=dev
 // --wasm-execution=compiled
 // --pallet=pallet_bridge_transfer
 // --extrinsic=*
 // --heap-pages=4096
-// --steps=20
-// --repeat=50
-// --header=./LICENSE_HEADER
-// --template=./templates/benchmark/pallet-weight-template.hbs
+// --steps=50
+// --repeat=20
+// --header=./.maintain/header.txt
+// --template=./.maintain/frame-weight-template.hbs
 // --output=./pallets/bridge-transfer/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -49,49 +47,129 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn transfer_assets() -> Weight;
 	fn transfer() -> Weight;
+	fn set_recipient_format() -> Weight;
+	fn transfer_to_evm() -> Weight;
+	fn transfer_assets_batch(n: u32) -> Weight;
+	fn transfer_assets_with_call(n: u32) -> Weight;
+	fn set_payload_size_limit() -> Weight;
+	fn transfer_and_call_evm() -> Weight;
 }
 
 /// Weights for pallet_bridge_transfer using the Litentry node and recommended hardware.
 pub struct LitentryWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
-	// Storage: BridgeTransfer ExternalBalances (r:1 w:1)
+	// Storage: BridgeTransfer RecipientFormats (r:1 w:0)
 	// Storage: ChainBridge ChainNonces (r:1 w:1)
-	// Storage: ChainBridge BridgeFee (r:1 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: ChainBridge Withdrawals (r:0 w:1)
+	// Storage: ChainBridge WithdrawalExpiries (r:1 w:1)
 	// Storage: ChainBridge BridgeEvents (r:1 w:1)
 	fn transfer_assets() -> Weight {
-		Weight::from_parts(59_963_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+		Weight::from_parts(41_573_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
-	// Storage: BridgeTransfer MaximumIssuance (r:1 w:0)
-	// Storage: BridgeTransfer ExternalBalances (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
 	fn transfer() -> Weight {
-		Weight::from_parts(29_134_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		Weight::from_parts(14_712_000 as u64, 0)
+	}
+	// Storage: BridgeTransfer RecipientFormats (r:0 w:1)
+	fn set_recipient_format() -> Weight {
+		Weight::from_parts(11_262_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn transfer_to_evm() -> Weight {
+		Weight::from_parts(19_449_000 as u64, 0)
+	}
+	// Storage: BridgeTransfer RecipientFormats (r:1 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Withdrawals (r:0 w:64)
+	// Storage: ChainBridge WithdrawalExpiries (r:1 w:1)
+	// Storage: ChainBridge BridgeEvents (r:1 w:1)
+	/// The range of component `n` is `[1, 64]`.
+	fn transfer_assets_batch(n: u32, ) -> Weight {
+		Weight::from_parts(12_402_000 as u64, 0)
+			.saturating_add(Weight::from_parts(33_928_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: BridgeTransfer PayloadSizeLimits (r:1 w:0)
+	// Storage: BridgeTransfer RecipientFormats (r:1 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Withdrawals (r:0 w:1)
+	// Storage: ChainBridge WithdrawalExpiries (r:1 w:1)
+	// Storage: ChainBridge BridgeEvents (r:1 w:1)
+	/// The range of component `n` is `[0, 8192]`.
+	fn transfer_assets_with_call(n: u32, ) -> Weight {
+		Weight::from_parts(45_127_000 as u64, 0)
+			.saturating_add(Weight::from_parts(1_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: BridgeTransfer PayloadSizeLimits (r:0 w:1)
+	fn set_payload_size_limit() -> Weight {
+		Weight::from_parts(11_074_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn transfer_and_call_evm() -> Weight {
+		Weight::from_parts(29_399_000 as u64, 0)
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	// Storage: BridgeTransfer ExternalBalances (r:1 w:1)
+	// Storage: BridgeTransfer RecipientFormats (r:1 w:0)
 	// Storage: ChainBridge ChainNonces (r:1 w:1)
-	// Storage: ChainBridge BridgeFee (r:1 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: ChainBridge Withdrawals (r:0 w:1)
+	// Storage: ChainBridge WithdrawalExpiries (r:1 w:1)
 	// Storage: ChainBridge BridgeEvents (r:1 w:1)
 	fn transfer_assets() -> Weight {
-		Weight::from_parts(59_963_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+		Weight::from_parts(41_573_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
-	// Storage: BridgeTransfer MaximumIssuance (r:1 w:0)
-	// Storage: BridgeTransfer ExternalBalances (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
 	fn transfer() -> Weight {
-		Weight::from_parts(29_134_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+		Weight::from_parts(14_712_000 as u64, 0)
+	}
+	// Storage: BridgeTransfer RecipientFormats (r:0 w:1)
+	fn set_recipient_format() -> Weight {
+		Weight::from_parts(11_262_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn transfer_to_evm() -> Weight {
+		Weight::from_parts(19_449_000 as u64, 0)
+	}
+	// Storage: BridgeTransfer RecipientFormats (r:1 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Withdrawals (r:0 w:64)
+	// Storage: ChainBridge WithdrawalExpiries (r:1 w:1)
+	// Storage: ChainBridge BridgeEvents (r:1 w:1)
+	/// The range of component `n` is `[1, 64]`.
+	fn transfer_assets_batch(n: u32, ) -> Weight {
+		Weight::from_parts(12_402_000 as u64, 0)
+			.saturating_add(Weight::from_parts(33_928_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: BridgeTransfer PayloadSizeLimits (r:1 w:0)
+	// Storage: BridgeTransfer RecipientFormats (r:1 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Withdrawals (r:0 w:1)
+	// Storage: ChainBridge WithdrawalExpiries (r:1 w:1)
+	// Storage: ChainBridge BridgeEvents (r:1 w:1)
+	/// The range of component `n` is `[0, 8192]`.
+	fn transfer_assets_with_call(n: u32, ) -> Weight {
+		Weight::from_parts(45_127_000 as u64, 0)
+			.saturating_add(Weight::from_parts(1_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: BridgeTransfer PayloadSizeLimits (r:0 w:1)
+	fn set_payload_size_limit() -> Weight {
+		Weight::from_parts(11_074_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn transfer_and_call_evm() -> Weight {
+		Weight::from_parts(29_399_000 as u64, 0)
 	}
 }
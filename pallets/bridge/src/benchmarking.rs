@@ -22,52 +22,161 @@
 #![allow(clippy::useless_vec)]
 use super::*;
 use crate::{BridgeChainId, Call, Event, Pallet as bridge};
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
 use frame_system::{Call as SystemCall, RawOrigin};
+use sp_core::{crypto::KeyTypeId, ecdsa, H160, H256};
 use sp_std::{boxed::Box, vec, vec::Vec};
 
 const USER_SEED: u32 = 9966;
+const SIGNER_KEY_TYPE: KeyTypeId = KeyTypeId(*b"brdg");
 
-fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
-fn make_proposal<T: Config>(remark: Vec<u8>) -> T::Proposal {
+fn make_proposal<T: Config<I>, I: 'static>(remark: Vec<u8>) -> T::Proposal {
 	SystemCall::<T>::remark { remark }.into()
 }
 
-benchmarks! {
+/// Adds `r` relayers with signing keys, each membership change snapshots all of their keys
+fn setup_relayers<T: Config<I>, I: 'static>(
+	r: u32,
+) -> Result<Vec<T::AccountId>, sp_runtime::DispatchError> {
+	let mut relayers = Vec::new();
+	for i in 0..r {
+		let relayer_id: T::AccountId = account("RELAYER", i, USER_SEED);
+		bridge::<T, I>::register_relayer(relayer_id.clone())?;
+		RelayerSigners::<T, I>::insert(&relayer_id, H160::from_low_u64_be(i as u64 + 1));
+		relayers.push(relayer_id);
+	}
+	Ok(relayers)
+}
+
+benchmarks_instance_pallet! {
 	set_threshold{
+		let r in 1 .. T::MaxRelayers::get();
+		setup_relayers::<T, I>(r)?;
 		let i = 100u32;
 	}:_(RawOrigin::Root,i)
 	verify{
-		assert_eq!(RelayerThreshold::<T>::get(),i);
+		assert_eq!(RelayerThreshold::<T, I>::get(),i);
 	}
 
 	whitelist_chain{
 		let bridgechain_id = T::BridgeChainId::get().saturating_add(1);
 	}:_(RawOrigin::Root,bridgechain_id)
 	verify{
-		assert!(ChainNonces::<T>::contains_key(bridgechain_id));
+		assert!(ChainNonces::<T, I>::contains_key(bridgechain_id));
 	}
 
 	add_relayer{
+		let r in 1 .. T::MaxRelayers::get() - 1;
+		setup_relayers::<T, I>(r)?;
 		let relayer_id: T::AccountId = account("TEST_A", 0u32, USER_SEED);
 	}:_(RawOrigin::Root,relayer_id.clone())
 	verify{
-		assert!(Relayers::<T>::contains_key(relayer_id));
+		assert!(Relayers::<T, I>::contains_key(relayer_id));
 	}
 
 	remove_relayer{
-		let relayer_id: T::AccountId = account("TEST_B", 0u32, USER_SEED);
+		let r in 1 .. T::MaxRelayers::get();
+		// removing a relayer with a signing key rotates the relayer set
+		let relayer_id = setup_relayers::<T, I>(r)?[0].clone();
+	}:_(RawOrigin::Root,relayer_id.clone())
+	verify{
+		  assert!(!Relayers::<T, I>::contains_key(relayer_id));
+	}
 
-		bridge::<T>::add_relayer(
+	set_relayer_signer{
+		let r in 1 .. T::MaxRelayers::get() - 1;
+		setup_relayers::<T, I>(r)?;
+		let relayer_id: T::AccountId = account("TEST_A", 0u32, USER_SEED);
+
+		bridge::<T, I>::add_relayer(
 			RawOrigin::Root.into(),
 			relayer_id.clone()
 		)?;
-	}:_(RawOrigin::Root,relayer_id.clone())
+
+		let message_hash = hashing::keccak_256(&bridge::<T, I>::relayer_signer_message(&relayer_id));
+		let public = sp_io::crypto::ecdsa_generate(SIGNER_KEY_TYPE, None);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(SIGNER_KEY_TYPE, &public, &message_hash)
+			.ok_or("Keystore unavailable")?;
+		let signer = bridge::<T, I>::recover_signer(&signature, &message_hash)
+			.ok_or("InvalidSignature")?;
+	}:_(RawOrigin::Signed(relayer_id.clone()),signer,signature)
+	verify{
+		assert_eq!(RelayerSigners::<T, I>::get(relayer_id),Some(signer));
+	}
+
+	submit_relayer_set_signature{
+		let r in 1 .. T::MaxRelayers::get() - 1;
+		setup_relayers::<T, I>(r)?;
+
+		// a relayer of the outgoing set signs with a key of the keystore
+		let relayer_id: T::AccountId = account("TEST_A", 0u32, USER_SEED);
+		bridge::<T, I>::register_relayer(relayer_id.clone())?;
+		let public = sp_io::crypto::ecdsa_generate(SIGNER_KEY_TYPE, None);
+		let sign = |message_hash: &[u8; 32]| {
+			sp_io::crypto::ecdsa_sign_prehashed(SIGNER_KEY_TYPE, &public, message_hash)
+				.ok_or("Keystore unavailable")
+		};
+		let probe = hashing::keccak_256(b"probe");
+		let signer = bridge::<T, I>::recover_signer(&sign(&probe)?, &probe)
+			.ok_or("InvalidSignature")?;
+		RelayerSigners::<T, I>::insert(&relayer_id, signer);
+
+		// the outgoing set has a threshold of 1, the incoming one differs by its threshold
+		bridge::<T, I>::set_relayer_threshold(1)?;
+		bridge::<T, I>::set_relayer_threshold(2)?;
+		let set_id = bridge::<T, I>::relayer_set_id();
+		let set = bridge::<T, I>::relayer_sets(set_id).ok_or("RelayerSetNotFound")?;
+		let message_hash = hashing::keccak_256(
+			&bridge::<T, I>::relayer_set_change_message(set_id, &set)
+		);
+		let signature = sign(&message_hash)?;
+		let caller: T::AccountId = account("TEST_B", 0u32, USER_SEED);
+	}:_(RawOrigin::Signed(caller),set_id,signature)
+	verify{
+		assert_last_event::<T, I>(Event::<T, I>::RelayerSetChangeSigned(set_id).into());
+	}
+
+	submit_withdrawal_signature{
+		let r in 1 .. T::MaxRelayers::get() - 1;
+		let signers: Vec<H160> = setup_relayers::<T, I>(r)?
+			.iter()
+			.filter_map(|relayer_id| RelayerSigners::<T, I>::get(relayer_id))
+			.collect();
+
+		let relayer_id: T::AccountId = account("TEST_A", 0u32, USER_SEED);
+		bridge::<T, I>::register_relayer(relayer_id.clone())?;
+		let public = sp_io::crypto::ecdsa_generate(SIGNER_KEY_TYPE, None);
+		let probe = hashing::keccak_256(b"probe");
+		let probe_signature = sp_io::crypto::ecdsa_sign_prehashed(SIGNER_KEY_TYPE, &public, &probe)
+			.ok_or("Keystore unavailable")?;
+		let signer = bridge::<T, I>::recover_signer(&probe_signature, &probe)
+			.ok_or("InvalidSignature")?;
+		RelayerSigners::<T, I>::insert(&relayer_id, signer);
+		// the last signature meets the threshold
+		bridge::<T, I>::set_relayer_threshold(r + 1)?;
+
+		let dest_id: BridgeChainId = T::BridgeChainId::get().saturating_add(1);
+		let resource_id: ResourceId = derive_resource_id(dest_id, b"transfer");
+		bridge::<T, I>::whitelist(dest_id)?;
+		bridge::<T, I>::signal_transfer_fungible(dest_id, resource_id, vec![0u8; 20], 100u32.into())?;
+		let withdrawal = bridge::<T, I>::withdrawals(dest_id, 1).ok_or("WithdrawalNotFound")?;
+		let digest = bridge::<T, I>::withdrawal_digest(dest_id, 1, &withdrawal);
+
+		// the other relayers have signed already, their signatures are checked against the set
+		let signatures: Vec<(H160, ecdsa::Signature)> = signers
+			.into_iter()
+			.map(|s| (s, ecdsa::Signature::from_raw([0u8; 65])))
+			.collect();
+		WithdrawalSignatures::<T, I>::insert(dest_id, 1, signatures);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(SIGNER_KEY_TYPE, &public, &digest)
+			.ok_or("Keystore unavailable")?;
+	}:_(RawOrigin::Signed(relayer_id),dest_id,1,signature)
 	verify{
-		  assert!(!Relayers::<T>::contains_key(relayer_id));
+		assert_last_event::<T, I>(Event::<T, I>::WithdrawalSigned(dest_id, 1, H256(digest)).into());
 	}
 
 	acknowledge_proposal{
@@ -76,22 +185,22 @@ benchmarks! {
 		let src_id:BridgeChainId = T::BridgeChainId::get().saturating_add(1);
 		let r_id:ResourceId = derive_resource_id(src_id, b"remark");
 
-		let proposal = make_proposal::<T>(vec![]);
+		let proposal = make_proposal::<T, I>(vec![]);
 		let method = vec![0u8];
 
-		bridge::<T>::add_relayer(
+		bridge::<T, I>::add_relayer(
 			RawOrigin::Root.into(),
 			relayer_id.clone(),
 		)?;
 
-		bridge::<T>::whitelist_chain(
+		bridge::<T, I>::whitelist_chain(
 			RawOrigin::Root.into(),
 			src_id,
 		)?;
 
 	}:_(RawOrigin::Signed(relayer_id),prop_id,src_id, r_id, Box::new(proposal))
 	verify{
-		assert_last_event::<T>(Event::ProposalSucceeded(src_id, prop_id).into());
+		assert_last_event::<T, I>(Event::<T, I>::ProposalSucceeded(src_id, prop_id).into());
 	}
 
 	reject_proposal{
@@ -100,23 +209,23 @@ benchmarks! {
 		let src_id:BridgeChainId = T::BridgeChainId::get().saturating_add(1);
 		let r_id:ResourceId = derive_resource_id(src_id, b"remark");
 
-		let proposal = make_proposal::<T>(vec![]);
+		let proposal = make_proposal::<T, I>(vec![]);
 
 		let method = vec![0u8];
 
-		bridge::<T>::add_relayer(
+		bridge::<T, I>::add_relayer(
 			RawOrigin::Root.into(),
 			relayer_id.clone(),
 		)?;
 
-		bridge::<T>::whitelist_chain(
+		bridge::<T, I>::whitelist_chain(
 			RawOrigin::Root.into(),
 			src_id,
 		)?;
 
 	}:_(RawOrigin::Signed(relayer_id),prop_id,src_id,r_id,Box::new(proposal))
 	verify{
-		assert_last_event::<T>(Event::ProposalRejected(src_id,prop_id).into());
+		assert_last_event::<T, I>(Event::<T, I>::ProposalRejected(src_id,prop_id).into());
 	}
 
 	eval_vote_state{
@@ -128,30 +237,30 @@ benchmarks! {
 		let src_id:BridgeChainId = T::BridgeChainId::get().saturating_add(1);
 		let r_id:ResourceId = derive_resource_id(src_id, b"remark");
 
-		let proposal = make_proposal::<T>(vec![]);
+		let proposal = make_proposal::<T, I>(vec![]);
 		let method = vec![0u8];
 
-		bridge::<T>::add_relayer(
+		bridge::<T, I>::add_relayer(
 			RawOrigin::Root.into(),
 			relayer_id_a,
 		)?;
 
-		bridge::<T>::add_relayer(
+		bridge::<T, I>::add_relayer(
 			RawOrigin::Root.into(),
 			relayer_id_b.clone(),
 		)?;
 
-		bridge::<T>::add_relayer(
+		bridge::<T, I>::add_relayer(
 			RawOrigin::Root.into(),
 			relayer_id_c.clone(),
 		)?;
 
-		bridge::<T>::whitelist_chain(
+		bridge::<T, I>::whitelist_chain(
 			RawOrigin::Root.into(),
 			src_id,
 		)?;
 
-		bridge::<T>::reject_proposal(
+		bridge::<T, I>::reject_proposal(
 			RawOrigin::Signed(relayer_id_b).into(),
 			prop_id,
 			src_id,
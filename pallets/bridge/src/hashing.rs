@@ -15,8 +15,71 @@
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
 use sp_core_hashing::blake2_128 as impl_blake2_128;
+use sp_std::vec::Vec;
 
 /// Do a Blake2 128-bit hash and return result.
 pub fn blake2_128(data: &[u8]) -> [u8; 16] {
 	impl_blake2_128(data)
 }
+
+/// Do a Keccak 256-bit hash and return result.
+pub fn keccak_256(data: &[u8]) -> [u8; 32] {
+	sp_core_hashing::keccak_256(data)
+}
+
+/// Hashes a pair of nodes in sorted order, as done by OpenZeppelin's `MerkleProof`.
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+	let mut buf = [0u8; 64];
+	if a <= b {
+		buf[..32].copy_from_slice(a);
+		buf[32..].copy_from_slice(b);
+	} else {
+		buf[..32].copy_from_slice(b);
+		buf[32..].copy_from_slice(a);
+	}
+	keccak_256(&buf)
+}
+
+/// Builds the next layer of a binary Merkle tree. An odd node is promoted as is.
+fn next_layer(layer: &[[u8; 32]]) -> Vec<[u8; 32]> {
+	layer
+		.chunks(2)
+		.map(|pair| if pair.len() == 2 { hash_pair(&pair[0], &pair[1]) } else { pair[0] })
+		.collect()
+}
+
+/// Computes the root of a sorted-pair keccak Merkle tree over `leaves`.
+/// Returns the zero hash for an empty set.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+	if leaves.is_empty() {
+		return [0u8; 32];
+	}
+	let mut layer = leaves.to_vec();
+	while layer.len() > 1 {
+		layer = next_layer(&layer);
+	}
+	layer[0]
+}
+
+/// Computes the Merkle proof of the leaf at `index`, `None` if out of range.
+pub fn merkle_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+	if index >= leaves.len() {
+		return None;
+	}
+	let mut proof = Vec::new();
+	let mut layer = leaves.to_vec();
+	while layer.len() > 1 {
+		let sibling = index ^ 1;
+		if sibling < layer.len() {
+			proof.push(layer[sibling]);
+		}
+		layer = next_layer(&layer);
+		index /= 2;
+	}
+	Some(proof)
+}
+
+/// Verifies a proof produced by [`merkle_proof`] against `root`.
+pub fn verify_merkle_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+	proof.iter().fold(leaf, |acc, node| hash_pair(&acc, node)) == *root
+}
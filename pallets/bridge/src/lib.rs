@@ -26,13 +26,14 @@ mod tests;
 mod benchmarking;
 
 pub mod hashing;
+pub mod runtime_api;
 pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::weights::WeightInfo;
+	use crate::{hashing, weights::WeightInfo};
 	use codec::{Codec, EncodeLike};
 	use frame_support::dispatch::GetDispatchInfo;
 	pub use frame_support::{pallet_prelude::*, traits::StorageVersion, PalletId, Parameter};
@@ -40,6 +41,7 @@ pub mod pallet {
 		pallet_prelude::*,
 		{self as system},
 	};
+	use sp_core::{ecdsa, H160, H256};
 	use sp_runtime::{
 		traits::{AccountIdConversion, AtLeast32BitUnsigned, Dispatchable},
 		FixedPointOperand, SaturatedConversion,
@@ -48,12 +50,12 @@ pub mod pallet {
 	use sp_std::{fmt::Debug, prelude::*, vec};
 
 	const DEFAULT_RELAYER_THRESHOLD: u32 = 1;
-	const MODULE_ID: PalletId = PalletId(*b"litry/bg");
 
 	pub type BridgeChainId = u8;
 	pub type DepositNonce = u64;
 	pub type ResourceId = [u8; 32];
-	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type RelayerSetId = u64;
+	pub type BalanceOf<T, I = ()> = <T as Config<I>>::Balance;
 
 	/// Helper function to concatenate a chain ID and some bytes to produce a resource ID.
 	/// The common format is (31 bytes unique ID + 1 byte chain ID).
@@ -97,6 +99,59 @@ pub mod pallet {
 		NonFungibleTransfer(BridgeChainId, DepositNonce, ResourceId, Vec<u8>, Vec<u8>, Vec<u8>),
 		#[codec(index = 2)]
 		GenericTransfer(BridgeChainId, DepositNonce, ResourceId, Vec<u8>),
+		#[codec(index = 3)]
+		FungibleTransferWithCall(BridgeChainId, DepositNonce, ResourceId, u128, Vec<u8>, Vec<u8>),
+	}
+
+	/// A snapshot of the relayers' signing keys, as known by counterpart chain contracts.
+	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct RelayerSet {
+		/// Ethereum addresses of the relayers' signing keys, sorted ascending
+		pub signers: Vec<H160>,
+		/// Number of signatures required by the set
+		pub threshold: u32,
+	}
+
+	impl RelayerSet {
+		/// Merkle leaves of the signers, `keccak256(abi.encodePacked(signer))`
+		pub fn leaves(&self) -> Vec<[u8; 32]> {
+			self.signers.iter().map(|s| hashing::keccak_256(s.as_bytes())).collect()
+		}
+
+		/// Root of the sorted-pair keccak Merkle tree over the signers
+		pub fn root(&self) -> H256 {
+			H256(hashing::merkle_root(&self.leaves()))
+		}
+	}
+
+	/// Everything a counterpart chain contract needs to rotate from set `set_id - 1` to
+	/// set `set_id`.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct RelayerSetChangeProof {
+		/// Id of the incoming set
+		pub set_id: RelayerSetId,
+		/// The incoming set
+		pub relayer_set: RelayerSet,
+		/// ABI encoded set change message
+		pub message: Vec<u8>,
+		/// `keccak256(message)`, the digest signed by the outgoing set
+		pub message_hash: H256,
+		/// Merkle root of the outgoing set
+		pub outgoing_root: H256,
+		/// Signatures of the outgoing set over `message_hash`
+		pub signatures: Vec<(H160, ecdsa::Signature)>,
+		/// Merkle proof of each signer in `signatures` against `outgoing_root`
+		pub proofs: Vec<Vec<H256>>,
+	}
+
+	/// An outbound fungible transfer awaiting relayer signatures for the destination contract.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct OutboundWithdrawal {
+		pub resource_id: ResourceId,
+		pub amount: u128,
+		pub recipient: Vec<u8>,
+		/// Calldata executed by the destination handler, if any
+		pub payload: Option<Vec<u8>>,
 	}
 
 	impl<A: PartialEq, B: PartialOrd + Default> ProposalVotes<A, B> {
@@ -146,11 +201,12 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Origin used to administer the pallet
 		type BridgeCommitteeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Proposed dispatchable call
@@ -166,6 +222,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type BridgeChainId: Get<BridgeChainId>;
 
+		/// The bridge's pallet id, used for deriving its sovereign account ID.
+		/// Every bridge instance must use a distinct id.
+		#[pallet::constant]
+		type BridgePalletId: Get<PalletId>;
+
 		/// The units in which we record balances.
 		type Balance: Parameter
 			+ Member
@@ -182,13 +243,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProposalLifetime: Get<BlockNumberFor<Self>>;
 
+		/// Maximum number of relayers. Each membership change snapshots the signing keys of all
+		/// relayers, so it bounds the weight of the relayer management calls.
+		#[pallet::constant]
+		type MaxRelayers: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Vote threshold has changed (new_threshold)
 		RelayerThresholdChanged(u32),
 		/// Chain now available for transfers (chain_id)
@@ -205,6 +271,9 @@ pub mod pallet {
 		NonFungibleTransfer(BridgeChainId, DepositNonce, ResourceId, Vec<u8>, Vec<u8>, Vec<u8>),
 		/// GenericTransfer is for a generic data payload (dest_id, nonce, resource_id, metadata)
 		GenericTransfer(BridgeChainId, DepositNonce, ResourceId, Vec<u8>),
+		/// FungibleTransferWithCall is for relaying fungibles together with calldata executed by
+		/// the destination handler (dest_id, nonce, resource_id, amount, recipient, payload)
+		FungibleTransferWithCall(BridgeChainId, DepositNonce, ResourceId, u128, Vec<u8>, Vec<u8>),
 		/// Vote submitted in favour of proposal
 		VoteFor(BridgeChainId, DepositNonce, T::AccountId),
 		/// Vot submitted against proposal
@@ -217,10 +286,24 @@ pub mod pallet {
 		ProposalSucceeded(BridgeChainId, DepositNonce),
 		/// Execution of call failed
 		ProposalFailed(BridgeChainId, DepositNonce),
+		/// Relayer signing key updated (relayer, signer)
+		RelayerSignerSet(T::AccountId, H160),
+		/// Relayer set rotated, the message must be signed by the previous set (set_id,
+		/// message_hash)
+		RelayerSetChanged(RelayerSetId, H256),
+		/// Signature over a set change message accepted (set_id, signer)
+		RelayerSetSignatureSubmitted(RelayerSetId, H160),
+		/// Set change message has reached the threshold of the previous set (set_id)
+		RelayerSetChangeSigned(RelayerSetId),
+		/// Relayer signature over an outbound withdrawal accepted (dest_id, nonce, signer)
+		WithdrawalSignatureSubmitted(BridgeChainId, DepositNonce, H160),
+		/// Outbound withdrawal has reached the relayer threshold and can be claimed on the
+		/// destination chain (dest_id, nonce, digest)
+		WithdrawalSigned(BridgeChainId, DepositNonce, H256),
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// Relayer threshold not set
 		ThresholdNotSet,
 		/// Provided chain Id is not valid
@@ -235,6 +318,8 @@ pub mod pallet {
 		ResourceDoesNotExist,
 		/// Relayer already in set
 		RelayerAlreadyExists,
+		/// The relayer set already has `MaxRelayers` relayers
+		TooManyRelayers,
 		/// Provided accountId is not a relayer
 		RelayerInvalid,
 		/// Protected operation, must be performed by relayer
@@ -252,11 +337,26 @@ pub mod pallet {
 		/// Lifetime of proposal has been exceeded
 		ProposalExpired,
 		NonceOverFlow,
+		/// No relayer set with the given ID exists
+		RelayerSetNotFound,
+		/// The relayer set has no predecessor whose signatures could be collected
+		NoPreviousRelayerSet,
+		/// Signature could not be recovered
+		InvalidSignature,
+		/// Recovered signer is not part of the previous relayer set
+		SignerNotInRelayerSet,
+		/// The signer has already signed this message
+		SignerAlreadySigned,
+		/// Relayer has no signing key registered
+		RelayerSignerNotSet,
+		/// No outbound withdrawal with the given destination and nonce exists
+		WithdrawalNotFound,
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn chains)]
-	pub type ChainNonces<T> = StorageMap<_, Blake2_256, BridgeChainId, DepositNonce>;
+	pub type ChainNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_256, BridgeChainId, DepositNonce>;
 
 	#[pallet::type_value]
 	pub fn DefaultRelayerThresholdValue() -> u32 {
@@ -265,19 +365,21 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn relayer_threshold)]
-	pub type RelayerThreshold<T> = StorageValue<_, u32, ValueQuery, DefaultRelayerThresholdValue>;
+	pub type RelayerThreshold<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery, DefaultRelayerThresholdValue>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn relayers)]
-	pub type Relayers<T: Config> = StorageMap<_, Blake2_256, T::AccountId, bool, ValueQuery>;
+	pub type Relayers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_256, T::AccountId, bool, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn relayer_count)]
-	pub type RelayerCount<T> = StorageValue<_, u32, ValueQuery>;
+	pub type RelayerCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn votes)]
-	pub type Votes<T: Config> = StorageDoubleMap<
+	pub type Votes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_256,
 		BridgeChainId,
@@ -292,29 +394,100 @@ pub mod pallet {
 	// For more details, see at: https://github.com/litentry/ChainBridge/blob/main/chains/substrate/listener.go#L186-L237
 	#[pallet::storage]
 	#[pallet::getter(fn bridge_events)]
-	pub type BridgeEvents<T> = StorageValue<_, Vec<BridgeEvent>, ValueQuery>;
+	pub type BridgeEvents<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<BridgeEvent>, ValueQuery>;
+
+	/// Signing key of each relayer, used by counterpart chain contracts to verify relayers
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_signers)]
+	pub type RelayerSigners<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, H160>;
+
+	/// Id of the current relayer set, bumped on each membership change
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_set_id)]
+	pub type CurrentRelayerSetId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, RelayerSetId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_sets)]
+	pub type RelayerSets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, RelayerSetId, RelayerSet>;
+
+	/// Signatures of set `set_id - 1` over the message rotating into set `set_id`
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_set_signatures)]
+	pub type RelayerSetSignatures<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, RelayerSetId, Vec<(H160, ecdsa::Signature)>, ValueQuery>;
+
+	/// Outbound fungible transfers, to be signed by the relayers
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawals)]
+	pub type Withdrawals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BridgeChainId,
+		Twox64Concat,
+		DepositNonce,
+		OutboundWithdrawal,
+	>;
+
+	/// Relayer signatures over the EIP-712 digest of each outbound withdrawal
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_signatures)]
+	pub type WithdrawalSignatures<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BridgeChainId,
+		Twox64Concat,
+		DepositNonce,
+		Vec<(H160, ecdsa::Signature)>,
+		ValueQuery,
+	>;
+
+	/// Outbound withdrawals expiring at each block, along with their signatures
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_expiries)]
+	pub type WithdrawalExpiries<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Vec<(BridgeChainId, DepositNonce)>,
+		ValueQuery,
+	>;
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			// Clear all bridge transfer data
-			BridgeEvents::<T>::kill();
-			Weight::zero()
+			BridgeEvents::<T, I>::kill();
+
+			// signatures of expired withdrawals remain available in the state of past blocks
+			let expired = WithdrawalExpiries::<T, I>::take(n);
+			for (dest_id, nonce) in expired.iter() {
+				Withdrawals::<T, I>::remove(dest_id, nonce);
+				WithdrawalSignatures::<T, I>::remove(dest_id, nonce);
+			}
+			// 1 read, 1 write: `withdrawal_expiries`
+			// 2 writes per expired withdrawal: `withdrawals`, `withdrawal_signatures`
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(T::DbWeight::get().writes(2 * expired.len() as u64))
 		}
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Sets the vote threshold for proposals.
 		///
 		/// This threshold is used to determine how many votes are required
 		/// before a proposal is executed.
 		///
 		/// # <weight>
-		/// - O(1) lookup and insert
+		/// - O(R) where R is the number of relayers
 		/// # </weight>
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::set_threshold())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_threshold(T::MaxRelayers::get()))]
 		pub fn set_threshold(origin: OriginFor<T>, threshold: u32) -> DispatchResult {
 			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
 			Self::set_relayer_threshold(threshold)
@@ -326,7 +499,7 @@ pub mod pallet {
 		/// - O(1) lookup and insert
 		/// # </weight>
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::whitelist_chain())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::whitelist_chain())]
 		pub fn whitelist_chain(origin: OriginFor<T>, id: BridgeChainId) -> DispatchResult {
 			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
 			Self::whitelist(id)
@@ -335,10 +508,10 @@ pub mod pallet {
 		/// Adds a new relayer to the relayer set.
 		///
 		/// # <weight>
-		/// - O(1) lookup and insert
+		/// - O(R) where R is the number of relayers
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_relayer())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::add_relayer(T::MaxRelayers::get()))]
 		pub fn add_relayer(origin: OriginFor<T>, v: T::AccountId) -> DispatchResult {
 			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
 			Self::register_relayer(v)
@@ -347,10 +520,10 @@ pub mod pallet {
 		/// Removes an existing relayer from the set.
 		///
 		/// # <weight>
-		/// - O(1) lookup and removal
+		/// - O(R) where R is the number of relayers
 		/// # </weight>
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_relayer())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_relayer(T::MaxRelayers::get()))]
 		pub fn remove_relayer(origin: OriginFor<T>, v: T::AccountId) -> DispatchResult {
 			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
 			Self::unregister_relayer(v)
@@ -367,7 +540,7 @@ pub mod pallet {
 		#[pallet::call_index(4)]
 		#[pallet::weight({
 		let di = call.get_dispatch_info();
-		(< T as Config >::WeightInfo::acknowledge_proposal()
+		(< T as Config<I> >::WeightInfo::acknowledge_proposal()
 		.saturating_add(di.weight),
 		di.class)
 		})]
@@ -378,11 +551,11 @@ pub mod pallet {
 			// TODO: remove will require token bridge binary change
 			// Resource info is in proposal call
 			_r_id: ResourceId,
-			call: Box<<T as Config>::Proposal>,
+			call: Box<<T as Config<I>>::Proposal>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_relayer(&who), Error::<T>::MustBeRelayer);
-			ensure!(Self::chain_whitelisted(src_id), Error::<T>::ChainNotWhitelisted);
+			ensure!(Self::is_relayer(&who), Error::<T, I>::MustBeRelayer);
+			ensure!(Self::chain_whitelisted(src_id), Error::<T, I>::ChainNotWhitelisted);
 
 			Self::vote_for(who, nonce, src_id, call)
 		}
@@ -393,7 +566,7 @@ pub mod pallet {
 		/// - Fixed, since execution of proposal should not be included
 		/// # </weight>
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::reject_proposal())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::reject_proposal())]
 		pub fn reject_proposal(
 			origin: OriginFor<T>,
 			nonce: DepositNonce,
@@ -401,11 +574,11 @@ pub mod pallet {
 			// TODO: remove will require token bridge binary change
 			// Resource info is in proposal call
 			_r_id: ResourceId,
-			call: Box<<T as Config>::Proposal>,
+			call: Box<<T as Config<I>>::Proposal>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_relayer(&who), Error::<T>::MustBeRelayer);
-			ensure!(Self::chain_whitelisted(src_id), Error::<T>::ChainNotWhitelisted);
+			ensure!(Self::is_relayer(&who), Error::<T, I>::MustBeRelayer);
+			ensure!(Self::chain_whitelisted(src_id), Error::<T, I>::ChainNotWhitelisted);
 
 			Self::vote_against(who, nonce, src_id, call)
 		}
@@ -421,7 +594,7 @@ pub mod pallet {
 		#[pallet::call_index(6)]
 		#[pallet::weight({
 		let di = prop.get_dispatch_info();
-		(< T as Config >::WeightInfo::eval_vote_state()
+		(< T as Config<I> >::WeightInfo::eval_vote_state()
 		.saturating_add(di.weight),
 		di.class)
 		})]
@@ -429,15 +602,137 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			nonce: DepositNonce,
 			src_id: BridgeChainId,
-			prop: Box<<T as Config>::Proposal>,
+			prop: Box<<T as Config<I>>::Proposal>,
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 
 			Self::try_resolve_proposal(nonce, src_id, prop)
 		}
+
+		/// Sets the Ethereum address of the caller's signing key.
+		///
+		/// The key is used to sign relayer set changes for counterpart chain contracts.
+		/// `signature` proves the ownership of the key, it must be made by the key over
+		/// `keccak256(relayer_signer_message(caller))`.
+		///
+		/// # <weight>
+		/// - O(R) where R is the number of relayers
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_relayer_signer(T::MaxRelayers::get()))]
+		pub fn set_relayer_signer(
+			origin: OriginFor<T>,
+			signer: H160,
+			signature: ecdsa::Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_relayer(&who), Error::<T, I>::MustBeRelayer);
+			let message_hash = hashing::keccak_256(&Self::relayer_signer_message(&who));
+			ensure!(
+				Self::recover_signer(&signature, &message_hash) == Some(signer),
+				Error::<T, I>::InvalidSignature
+			);
+			RelayerSigners::<T, I>::insert(&who, signer);
+			Self::deposit_event(Event::RelayerSignerSet(who, signer));
+			Self::rotate_relayer_set();
+			Ok(())
+		}
+
+		/// Submits a signature of the previous relayer set over the message rotating into
+		/// `set_id`.
+		///
+		/// Anyone can submit, the signer is recovered from the signature.
+		///
+		/// # <weight>
+		/// - O(1) recovery, O(R) signer and signature lookup
+		/// # </weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::submit_relayer_set_signature(T::MaxRelayers::get())
+		)]
+		pub fn submit_relayer_set_signature(
+			origin: OriginFor<T>,
+			set_id: RelayerSetId,
+			signature: ecdsa::Signature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let set = Self::relayer_sets(set_id).ok_or(Error::<T, I>::RelayerSetNotFound)?;
+			let prev_set = set_id
+				.checked_sub(1)
+				.and_then(|id| Self::relayer_sets(id))
+				.filter(|s| !s.signers.is_empty())
+				.ok_or(Error::<T, I>::NoPreviousRelayerSet)?;
+
+			let message_hash = hashing::keccak_256(&Self::relayer_set_change_message(set_id, &set));
+			let signer = Self::recover_signer(&signature, &message_hash)
+				.ok_or(Error::<T, I>::InvalidSignature)?;
+			ensure!(prev_set.signers.contains(&signer), Error::<T, I>::SignerNotInRelayerSet);
+
+			let mut signatures = Self::relayer_set_signatures(set_id);
+			ensure!(
+				!signatures.iter().any(|(s, _)| s == &signer),
+				Error::<T, I>::SignerAlreadySigned
+			);
+			signatures.push((signer, signature));
+			let signed = signatures.len() as u32 == prev_set.threshold;
+			RelayerSetSignatures::<T, I>::insert(set_id, signatures);
+
+			Self::deposit_event(Event::RelayerSetSignatureSubmitted(set_id, signer));
+			if signed {
+				Self::deposit_event(Event::RelayerSetChangeSigned(set_id));
+			}
+			Ok(())
+		}
+
+		/// Submits the caller's signature over the EIP-712 digest of an outbound withdrawal.
+		///
+		/// Once the relayer threshold is met, the packed signatures let anyone claim the
+		/// withdrawal on the destination contract.
+		///
+		/// # <weight>
+		/// - O(1) recovery, O(R) signature lookup where R is the number of relayers
+		/// # </weight>
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::submit_withdrawal_signature(T::MaxRelayers::get())
+		)]
+		pub fn submit_withdrawal_signature(
+			origin: OriginFor<T>,
+			dest_id: BridgeChainId,
+			nonce: DepositNonce,
+			signature: ecdsa::Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_relayer(&who), Error::<T, I>::MustBeRelayer);
+			let signer = Self::relayer_signers(&who).ok_or(Error::<T, I>::RelayerSignerNotSet)?;
+			let withdrawal =
+				Self::withdrawals(dest_id, nonce).ok_or(Error::<T, I>::WithdrawalNotFound)?;
+
+			let digest = Self::withdrawal_digest(dest_id, nonce, &withdrawal);
+			ensure!(
+				Self::recover_signer(&signature, &digest) == Some(signer),
+				Error::<T, I>::InvalidSignature
+			);
+
+			let mut signatures = Self::withdrawal_signatures(dest_id, nonce);
+			ensure!(
+				!signatures.iter().any(|(s, _)| s == &signer),
+				Error::<T, I>::SignerAlreadySigned
+			);
+			signatures.push((signer, signature));
+			WithdrawalSignatures::<T, I>::insert(dest_id, nonce, signatures);
+			let signed = Self::current_withdrawal_signatures(dest_id, nonce).len() as u32 ==
+				Self::relayer_threshold();
+
+			Self::deposit_event(Event::WithdrawalSignatureSubmitted(dest_id, nonce, signer));
+			if signed {
+				Self::deposit_event(Event::WithdrawalSigned(dest_id, nonce, H256(digest)));
+			}
+			Ok(())
+		}
 	}
 
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// *** Utility methods ***
 
 		/// Checks if who is a relayer
@@ -448,7 +743,7 @@ pub mod pallet {
 		/// Provides an AccountId for the pallet.
 		/// This is used both as an origin check and deposit/withdrawal account.
 		pub fn account_id() -> T::AccountId {
-			MODULE_ID.into_account_truncating()
+			T::BridgePalletId::get().into_account_truncating()
 		}
 
 		/// Checks if a chain exists as a whitelisted destination
@@ -457,11 +752,11 @@ pub mod pallet {
 		}
 
 		/// Increments the deposit nonce for the specified chain ID
-		fn bump_nonce(id: BridgeChainId) -> Result<DepositNonce, Error<T>> {
+		fn bump_nonce(id: BridgeChainId) -> Result<DepositNonce, Error<T, I>> {
 			let nonce = Self::chains(id).unwrap_or_default();
-			let new_nonce = nonce.checked_add(1u64).ok_or(Error::<T>::NonceOverFlow);
+			let new_nonce = nonce.checked_add(1u64).ok_or(Error::<T, I>::NonceOverFlow);
 			if let Ok(nonce_inner) = &new_nonce {
-				ChainNonces::<T>::insert(id, nonce_inner);
+				ChainNonces::<T, I>::insert(id, nonce_inner);
 			}
 			new_nonce
 		}
@@ -470,42 +765,268 @@ pub mod pallet {
 
 		/// Set a new voting threshold
 		pub fn set_relayer_threshold(threshold: u32) -> DispatchResult {
-			ensure!(threshold > 0, Error::<T>::InvalidThreshold);
-			RelayerThreshold::<T>::put(threshold);
+			ensure!(threshold > 0, Error::<T, I>::InvalidThreshold);
+			RelayerThreshold::<T, I>::put(threshold);
 			Self::deposit_event(Event::RelayerThresholdChanged(threshold));
+			Self::rotate_relayer_set();
 			Ok(())
 		}
 
 		/// Whitelist a chain ID for transfer
 		pub fn whitelist(id: BridgeChainId) -> DispatchResult {
 			// Cannot whitelist this chain
-			ensure!(id != T::BridgeChainId::get(), Error::<T>::InvalidChainId);
+			ensure!(id != T::BridgeChainId::get(), Error::<T, I>::InvalidChainId);
 			// Cannot whitelist with an existing entry
-			ensure!(!Self::chain_whitelisted(id), Error::<T>::ChainAlreadyWhitelisted);
-			ChainNonces::<T>::insert(id, 0);
+			ensure!(!Self::chain_whitelisted(id), Error::<T, I>::ChainAlreadyWhitelisted);
+			ChainNonces::<T, I>::insert(id, 0);
 			Self::deposit_event(Event::ChainWhitelisted(id));
 			Ok(())
 		}
 
 		/// Adds a new relayer to the set
 		pub fn register_relayer(relayer: T::AccountId) -> DispatchResult {
-			ensure!(!Self::is_relayer(&relayer), Error::<T>::RelayerAlreadyExists);
-			Relayers::<T>::insert(&relayer, true);
-			RelayerCount::<T>::mutate(|i| *i += 1);
+			ensure!(!Self::is_relayer(&relayer), Error::<T, I>::RelayerAlreadyExists);
+			ensure!(Self::relayer_count() < T::MaxRelayers::get(), Error::<T, I>::TooManyRelayers);
+			Relayers::<T, I>::insert(&relayer, true);
+			RelayerCount::<T, I>::mutate(|i| *i += 1);
 
 			Self::deposit_event(Event::RelayerAdded(relayer));
+			Self::rotate_relayer_set();
 			Ok(())
 		}
 
 		/// Removes a relayer from the set
 		pub fn unregister_relayer(relayer: T::AccountId) -> DispatchResult {
-			ensure!(Self::is_relayer(&relayer), Error::<T>::RelayerInvalid);
-			Relayers::<T>::remove(&relayer);
-			RelayerCount::<T>::mutate(|i| *i -= 1);
+			ensure!(Self::is_relayer(&relayer), Error::<T, I>::RelayerInvalid);
+			Relayers::<T, I>::remove(&relayer);
+			RelayerSigners::<T, I>::remove(&relayer);
+			RelayerCount::<T, I>::mutate(|i| *i -= 1);
 			Self::deposit_event(Event::RelayerRemoved(relayer));
+			Self::rotate_relayer_set();
 			Ok(())
 		}
 
+		// *** Relayer set rotation methods ***
+
+		/// Snapshots the current relayers' signing keys and threshold.
+		pub fn current_relayer_set() -> RelayerSet {
+			// signers of removed relayers are cleared, so all entries belong to current relayers
+			let mut signers: Vec<H160> = RelayerSigners::<T, I>::iter_values().collect();
+			signers.sort();
+			signers.dedup();
+			RelayerSet { signers, threshold: Self::relayer_threshold() }
+		}
+
+		/// Starts a new relayer set if the membership differs from the current one.
+		fn rotate_relayer_set() {
+			let set = Self::current_relayer_set();
+			let set_id = Self::relayer_set_id();
+			if Self::relayer_sets(set_id).unwrap_or_default() == set {
+				return;
+			}
+			let new_id = set_id.saturating_add(1);
+			let message_hash = hashing::keccak_256(&Self::relayer_set_change_message(new_id, &set));
+			RelayerSets::<T, I>::insert(new_id, set);
+			CurrentRelayerSetId::<T, I>::put(new_id);
+			Self::deposit_event(Event::RelayerSetChanged(new_id, H256(message_hash)));
+		}
+
+		/// ABI encoded message rotating into `set`, i.e.
+		/// `abi.encode(typehash, bridgeId, chainId, setId, signersRoot, threshold)`.
+		///
+		/// `bridgeId` is the left-aligned `PalletId` so that instances can't replay each
+		/// other's messages.
+		pub fn relayer_set_change_message(set_id: RelayerSetId, set: &RelayerSet) -> Vec<u8> {
+			let typehash = hashing::keccak_256(
+				concat!(
+					"RelayerSetChange(bytes32 bridgeId,uint8 chainId,uint64 setId,",
+					"bytes32 signersRoot,uint32 threshold)"
+				)
+				.as_bytes(),
+			);
+
+			let mut message = Vec::with_capacity(32 * 6);
+			message.extend_from_slice(&typehash);
+			message.extend_from_slice(&Self::bridge_id());
+			message.extend_from_slice(&abi_word(T::BridgeChainId::get() as u128));
+			message.extend_from_slice(&abi_word(set_id as u128));
+			message.extend_from_slice(set.root().as_bytes());
+			message.extend_from_slice(&abi_word(set.threshold as u128));
+			message
+		}
+
+		/// ABI encoded message binding a signing key to `relayer`, i.e.
+		/// `abi.encode(typehash, bridgeId, chainId, keccak256(relayer), setId)`.
+		///
+		/// `relayer` is SCALE encoded, `setId` is the current relayer set id so that the
+		/// message can't be replayed once the set has rotated.
+		pub fn relayer_signer_message(relayer: &T::AccountId) -> Vec<u8> {
+			let typehash = hashing::keccak_256(
+				b"RelayerSigner(bytes32 bridgeId,uint8 chainId,bytes32 relayer,uint64 setId)",
+			);
+
+			let mut message = Vec::with_capacity(32 * 5);
+			message.extend_from_slice(&typehash);
+			message.extend_from_slice(&Self::bridge_id());
+			message.extend_from_slice(&abi_word(T::BridgeChainId::get() as u128));
+			message.extend_from_slice(&hashing::keccak_256(&relayer.encode()));
+			message.extend_from_slice(&abi_word(Self::relayer_set_id() as u128));
+			message
+		}
+
+		/// The left-aligned `PalletId`, so that instances can't replay each other's messages.
+		fn bridge_id() -> [u8; 32] {
+			let mut bridge_id = [0u8; 32];
+			bridge_id[..8].copy_from_slice(&T::BridgePalletId::get().0);
+			bridge_id
+		}
+
+		// *** Outbound withdrawal signing methods ***
+
+		/// EIP-712 domain separator of the destination contracts, i.e.
+		/// `keccak256(abi.encode(typehash, keccak256(name), keccak256(version), salt))`.
+		///
+		/// The salt is the bridge id with the source chain id in its last byte.
+		pub fn withdrawal_domain_separator() -> [u8; 32] {
+			let typehash =
+				hashing::keccak_256(b"EIP712Domain(string name,string version,bytes32 salt)");
+			let mut salt = Self::bridge_id();
+			salt[31] = T::BridgeChainId::get();
+
+			let mut encoded = Vec::with_capacity(32 * 4);
+			encoded.extend_from_slice(&typehash);
+			encoded.extend_from_slice(&hashing::keccak_256(b"Bridge"));
+			encoded.extend_from_slice(&hashing::keccak_256(b"1"));
+			encoded.extend_from_slice(&salt);
+			hashing::keccak_256(&encoded)
+		}
+
+		/// EIP-712 digest of an outbound withdrawal, i.e.
+		/// `keccak256("\x19\x01" ++ domainSeparator ++ hashStruct(withdrawal))`.
+		///
+		/// A withdrawal with a payload is typed as `WithdrawalWithCall`, which additionally
+		/// commits to `bytes payload`.
+		pub fn withdrawal_digest(
+			dest_id: BridgeChainId,
+			nonce: DepositNonce,
+			withdrawal: &OutboundWithdrawal,
+		) -> [u8; 32] {
+			let typehash = match withdrawal.payload {
+				None => hashing::keccak_256(
+					concat!(
+						"Withdrawal(uint8 destId,uint64 nonce,bytes32 resourceId,uint256 amount,",
+						"bytes recipient)"
+					)
+					.as_bytes(),
+				),
+				Some(_) => hashing::keccak_256(
+					concat!(
+						"WithdrawalWithCall(uint8 destId,uint64 nonce,bytes32 resourceId,",
+						"uint256 amount,bytes recipient,bytes payload)"
+					)
+					.as_bytes(),
+				),
+			};
+			let mut encoded = Vec::with_capacity(32 * 7);
+			encoded.extend_from_slice(&typehash);
+			encoded.extend_from_slice(&abi_word(dest_id as u128));
+			encoded.extend_from_slice(&abi_word(nonce as u128));
+			encoded.extend_from_slice(&withdrawal.resource_id);
+			encoded.extend_from_slice(&abi_word(withdrawal.amount));
+			encoded.extend_from_slice(&hashing::keccak_256(&withdrawal.recipient));
+			if let Some(payload) = &withdrawal.payload {
+				encoded.extend_from_slice(&hashing::keccak_256(payload));
+			}
+
+			let mut message = Vec::with_capacity(2 + 32 * 2);
+			message.extend_from_slice(b"\x19\x01");
+			message.extend_from_slice(&Self::withdrawal_domain_separator());
+			message.extend_from_slice(&hashing::keccak_256(&encoded));
+			hashing::keccak_256(&message)
+		}
+
+		/// Signatures over the withdrawal made by the signing keys of the current relayers.
+		///
+		/// Signatures of removed relayers and of replaced keys don't count anymore.
+		pub fn current_withdrawal_signatures(
+			dest_id: BridgeChainId,
+			nonce: DepositNonce,
+		) -> Vec<(H160, ecdsa::Signature)> {
+			// the latest relayer set is the snapshot of the current signing keys
+			let signers = Self::relayer_sets(Self::relayer_set_id()).unwrap_or_default().signers;
+			let mut signatures = Self::withdrawal_signatures(dest_id, nonce);
+			signatures.retain(|(signer, _)| signers.binary_search(signer).is_ok());
+			signatures
+		}
+
+		/// Signatures of the current relayers over the withdrawal packed as `r ++ s ++ v` (with
+		/// `v` in {27, 28}), ordered by signer address ascending. `None` until the relayer
+		/// threshold is met.
+		pub fn packed_withdrawal_signatures(
+			dest_id: BridgeChainId,
+			nonce: DepositNonce,
+		) -> Option<Vec<u8>> {
+			let mut signatures = Self::current_withdrawal_signatures(dest_id, nonce);
+			if signatures.is_empty() || (signatures.len() as u32) < Self::relayer_threshold() {
+				return None;
+			}
+			signatures.sort_by_key(|(signer, _)| *signer);
+
+			let mut packed = Vec::with_capacity(signatures.len() * 65);
+			for (_, signature) in signatures {
+				packed.extend_from_slice(&signature.0[..64]);
+				// the recovery id may be submitted either as {0, 1} or {27, 28}
+				let v = signature.0[64];
+				let v = if v >= 27 { v - 27 } else { v };
+				packed.push(v + 27);
+			}
+			Some(packed)
+		}
+
+		/// Recovers the Ethereum address that signed the 32-byte `digest`.
+		///
+		/// The recovery id must be in {0, 1} or {27, 28}, as destination contracts expect.
+		pub fn recover_signer(signature: &ecdsa::Signature, digest: &[u8; 32]) -> Option<H160> {
+			if !matches!(signature.0[64], 0 | 1 | 27 | 28) {
+				return None;
+			}
+			sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, digest)
+				.ok()
+				.map(|pubkey| H160::from_slice(&hashing::keccak_256(&pubkey)[12..]))
+		}
+
+		/// Returns the message, signatures and Merkle proofs rotating into `set_id`, once the
+		/// previous set's threshold is met.
+		pub fn relayer_set_change_proof(set_id: RelayerSetId) -> Option<RelayerSetChangeProof> {
+			let relayer_set = Self::relayer_sets(set_id)?;
+			let prev_set = Self::relayer_sets(set_id.checked_sub(1)?)?;
+			let signatures = Self::relayer_set_signatures(set_id);
+			if prev_set.signers.is_empty() || (signatures.len() as u32) < prev_set.threshold {
+				return None;
+			}
+
+			let leaves = prev_set.leaves();
+			let proofs = signatures
+				.iter()
+				.map(|(signer, _)| {
+					let index = prev_set.signers.iter().position(|s| s == signer)?;
+					hashing::merkle_proof(&leaves, index)
+						.map(|proof| proof.into_iter().map(H256).collect())
+				})
+				.collect::<Option<Vec<Vec<H256>>>>()?;
+			let message = Self::relayer_set_change_message(set_id, &relayer_set);
+
+			Some(RelayerSetChangeProof {
+				set_id,
+				message_hash: H256(hashing::keccak_256(&message)),
+				message,
+				outgoing_root: prev_set.root(),
+				relayer_set,
+				signatures,
+				proofs,
+			})
+		}
+
 		// *** Proposal voting and execution methods ***
 
 		/// Commits a vote for a proposal. If the proposal doesn't exist it will be created.
@@ -517,16 +1038,16 @@ pub mod pallet {
 			in_favour: bool,
 		) -> DispatchResult {
 			let now = <frame_system::Pallet<T>>::block_number();
-			let mut votes = match Votes::<T>::get(src_id, (nonce, prop.clone())) {
+			let mut votes = match Votes::<T, I>::get(src_id, (nonce, prop.clone())) {
 				Some(v) => v,
 				None =>
 					ProposalVotes { expiry: now + T::ProposalLifetime::get(), ..Default::default() },
 			};
 
 			// Ensure the proposal isn't complete and relayer hasn't already voted
-			ensure!(!votes.is_complete(), Error::<T>::ProposalAlreadyComplete);
-			ensure!(!votes.is_expired(now), Error::<T>::ProposalExpired);
-			ensure!(!votes.has_voted(&who), Error::<T>::RelayerAlreadyVoted);
+			ensure!(!votes.is_complete(), Error::<T, I>::ProposalAlreadyComplete);
+			ensure!(!votes.is_expired(now), Error::<T, I>::ProposalExpired);
+			ensure!(!votes.has_voted(&who), Error::<T, I>::RelayerAlreadyVoted);
 
 			if in_favour {
 				votes.votes_for.push(who.clone());
@@ -536,7 +1057,7 @@ pub mod pallet {
 				Self::deposit_event(Event::VoteAgainst(src_id, nonce, who));
 			}
 
-			Votes::<T>::insert(src_id, (nonce, prop), votes);
+			Votes::<T, I>::insert(src_id, (nonce, prop), votes);
 
 			Ok(())
 		}
@@ -547,14 +1068,14 @@ pub mod pallet {
 			src_id: BridgeChainId,
 			prop: Box<T::Proposal>,
 		) -> DispatchResult {
-			if let Some(mut votes) = Votes::<T>::get(src_id, (nonce, prop.clone())) {
+			if let Some(mut votes) = Votes::<T, I>::get(src_id, (nonce, prop.clone())) {
 				let now = <frame_system::Pallet<T>>::block_number();
-				ensure!(!votes.is_complete(), Error::<T>::ProposalAlreadyComplete);
-				ensure!(!votes.is_expired(now), Error::<T>::ProposalExpired);
+				ensure!(!votes.is_complete(), Error::<T, I>::ProposalAlreadyComplete);
+				ensure!(!votes.is_expired(now), Error::<T, I>::ProposalExpired);
 
-				let status =
-					votes.try_to_complete(RelayerThreshold::<T>::get(), RelayerCount::<T>::get());
-				Votes::<T>::insert(src_id, (nonce, prop.clone()), votes);
+				let status = votes
+					.try_to_complete(RelayerThreshold::<T, I>::get(), RelayerCount::<T, I>::get());
+				Votes::<T, I>::insert(src_id, (nonce, prop.clone()), votes);
 
 				match status {
 					ProposalStatus::Approved => Self::finalize_execution(src_id, nonce, prop),
@@ -562,7 +1083,7 @@ pub mod pallet {
 					_ => Ok(()),
 				}
 			} else {
-				Err(Error::<T>::ProposalDoesNotExist.into())
+				Err(Error::<T, I>::ProposalDoesNotExist.into())
 			}
 		}
 
@@ -616,12 +1137,22 @@ pub mod pallet {
 			dest_id: BridgeChainId,
 			resource_id: ResourceId,
 			to: Vec<u8>,
-			amount: BalanceOf<T>,
+			amount: BalanceOf<T, I>,
 		) -> DispatchResult {
-			ensure!(Self::chain_whitelisted(dest_id), Error::<T>::ChainNotWhitelisted);
+			ensure!(Self::chain_whitelisted(dest_id), Error::<T, I>::ChainNotWhitelisted);
 
 			let nonce = Self::bump_nonce(dest_id)?;
-			BridgeEvents::<T>::append(BridgeEvent::FungibleTransfer(
+			Self::insert_withdrawal(
+				dest_id,
+				nonce,
+				OutboundWithdrawal {
+					resource_id,
+					amount: amount.saturated_into::<u128>(),
+					recipient: to.clone(),
+					payload: None,
+				},
+			);
+			BridgeEvents::<T, I>::append(BridgeEvent::FungibleTransfer(
 				dest_id,
 				nonce,
 				resource_id,
@@ -637,14 +1168,73 @@ pub mod pallet {
 			));
 			Ok(())
 		}
+
+		/// Initiates a singal Event for fungible asset out of the chain, along with a payload
+		/// executed by the destination handler. This should be called by another pallet.
+		pub fn signal_transfer_fungible_with_call(
+			dest_id: BridgeChainId,
+			resource_id: ResourceId,
+			to: Vec<u8>,
+			amount: BalanceOf<T, I>,
+			payload: Vec<u8>,
+		) -> DispatchResult {
+			ensure!(Self::chain_whitelisted(dest_id), Error::<T, I>::ChainNotWhitelisted);
+
+			let nonce = Self::bump_nonce(dest_id)?;
+			Self::insert_withdrawal(
+				dest_id,
+				nonce,
+				OutboundWithdrawal {
+					resource_id,
+					amount: amount.saturated_into::<u128>(),
+					recipient: to.clone(),
+					payload: Some(payload.clone()),
+				},
+			);
+			BridgeEvents::<T, I>::append(BridgeEvent::FungibleTransferWithCall(
+				dest_id,
+				nonce,
+				resource_id,
+				amount.saturated_into::<u128>(),
+				to.clone(),
+				payload.clone(),
+			));
+			Self::deposit_event(Event::FungibleTransferWithCall(
+				dest_id,
+				nonce,
+				resource_id,
+				amount.saturated_into::<u128>(),
+				to,
+				payload,
+			));
+			Ok(())
+		}
+
+		/// Stores an outbound withdrawal to be signed for `ProposalLifetime` blocks.
+		fn insert_withdrawal(
+			dest_id: BridgeChainId,
+			nonce: DepositNonce,
+			withdrawal: OutboundWithdrawal,
+		) {
+			let expiry = <frame_system::Pallet<T>>::block_number() + T::ProposalLifetime::get();
+			Withdrawals::<T, I>::insert(dest_id, nonce, withdrawal);
+			WithdrawalExpiries::<T, I>::append(expiry, (dest_id, nonce));
+		}
+	}
+
+	/// Left-pads `value` into a 32-byte big-endian ABI word.
+	fn abi_word(value: u128) -> [u8; 32] {
+		let mut word = [0u8; 32];
+		word[16..].copy_from_slice(&value.to_be_bytes());
+		word
 	}
 
 	/// Simple ensure origin for the bridge account
-	pub struct EnsureBridge<T>(sp_std::marker::PhantomData<T>);
-	impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureBridge<T> {
+	pub struct EnsureBridge<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> EnsureOrigin<T::RuntimeOrigin> for EnsureBridge<T, I> {
 		type Success = T::AccountId;
 		fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
-			let bridge_id = MODULE_ID.into_account_truncating();
+			let bridge_id = Pallet::<T, I>::account_id();
 			o.into().and_then(|o| match o {
 				system::RawOrigin::Signed(who) if who == bridge_id => Ok(bridge_id),
 				r => Err(T::RuntimeOrigin::from(r)),
@@ -653,7 +1243,7 @@ pub mod pallet {
 
 		#[cfg(feature = "runtime-benchmarks")]
 		fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
-			let bridge_id = MODULE_ID.into_account_truncating();
+			let bridge_id = Pallet::<T, I>::account_id();
 			Ok(T::RuntimeOrigin::from(system::RawOrigin::Signed(bridge_id)))
 		}
 	}
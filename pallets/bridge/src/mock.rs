@@ -17,11 +17,14 @@
 use super::*;
 
 use frame_support::{
-	assert_ok, derive_impl, ord_parameter_types, parameter_types,
+	assert_ok, derive_impl,
+	instances::Instance1,
+	ord_parameter_types, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use frame_system::{self as system};
 use sp_core::{ConstU16, H256};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 	BuildStorage,
@@ -38,6 +41,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Bridge: bridge,
+		SecondBridge: bridge::<Instance1>,
 	}
 );
 
@@ -93,6 +97,8 @@ parameter_types! {
 	pub const TestChainId: u8 = 5;
 	pub const ProposalLifetime: u64 = 50;
 	pub const TreasuryAccount:u64 = 0x8;
+	pub const BridgePalletId: PalletId = PalletId(*b"litry/bg");
+	pub const SecondBridgePalletId: PalletId = PalletId(*b"test/bg2");
 }
 
 impl Config for Test {
@@ -100,8 +106,22 @@ impl Config for Test {
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Proposal = RuntimeCall;
 	type BridgeChainId = TestChainId;
+	type BridgePalletId = BridgePalletId;
 	type Balance = u64;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxRelayers = ConstU32<16>;
+	type WeightInfo = ();
+}
+
+impl Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Proposal = RuntimeCall;
+	type BridgeChainId = TestChainId;
+	type BridgePalletId = SecondBridgePalletId;
+	type Balance = u64;
+	type ProposalLifetime = ProposalLifetime;
+	type MaxRelayers = ConstU32<16>;
 	type WeightInfo = ();
 }
 
@@ -113,7 +133,7 @@ pub const ENDOWED_BALANCE: u64 = 100_000_000;
 pub const TEST_THRESHOLD: u32 = 2;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let bridge_id = PalletId(*b"litry/bg").into_account_truncating();
+	let bridge_id = BridgePalletId::get().into_account_truncating();
 	let treasury_account: u64 = 0x8;
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
@@ -126,6 +146,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	.assimilate_storage(&mut t)
 	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
+	// benchmarks sign with keys generated in the keystore
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
@@ -0,0 +1,49 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the bridge pallet.
+
+use crate::{
+	BridgeChainId, DepositNonce, PalletId, RelayerSet, RelayerSetChangeProof, RelayerSetId,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Each bridge instance is selected by its `BridgePalletId`, the queries return `None` for
+	/// an unknown one.
+	pub trait BridgeApi {
+		/// Id of the current relayer set
+		fn relayer_set_id(bridge_id: PalletId) -> Option<RelayerSetId>;
+
+		/// The relayer set with the given id
+		fn relayer_set(bridge_id: PalletId, set_id: RelayerSetId) -> Option<RelayerSet>;
+
+		/// Proof rotating the counterpart chain contract into `set_id`, `None` until the
+		/// previous set has signed the change
+		fn relayer_set_change_proof(
+			bridge_id: PalletId,
+			set_id: RelayerSetId,
+		) -> Option<RelayerSetChangeProof>;
+
+		/// Relayer signatures over an outbound withdrawal, packed as `r ++ s ++ v` for the
+		/// destination contract, `None` until the relayer threshold is met
+		fn withdrawal_signatures(
+			bridge_id: PalletId,
+			dest_id: BridgeChainId,
+			nonce: DepositNonce,
+		) -> Option<Vec<u8>>;
+	}
+}
@@ -16,15 +16,18 @@
 
 use super::{
 	mock::{
-		assert_events, new_test_ext, new_test_ext_initialized, Balances, Bridge, ProposalLifetime,
-		RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Test, TestChainId, ENDOWED_BALANCE,
-		RELAYER_A, RELAYER_B, RELAYER_C, TEST_THRESHOLD,
+		assert_events, new_test_ext, new_test_ext_initialized, Balances, Bridge, BridgePalletId,
+		ProposalLifetime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SecondBridge,
+		SecondBridgePalletId, System, Test, TestChainId, ENDOWED_BALANCE, RELAYER_A, RELAYER_B,
+		RELAYER_C, TEST_THRESHOLD,
 	},
 	pallet::Event as PalletEvent,
 	*,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use frame_system as system;
+use sp_core::{ecdsa, Pair, H160, H256};
+use sp_runtime::traits::AccountIdConversion;
 
 #[test]
 fn derive_ids() {
@@ -158,6 +161,53 @@ fn add_remove_relayer() {
 	})
 }
 
+#[test]
+fn relayer_set_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let max_relayers = <Test as Config>::MaxRelayers::get();
+		for relayer in 0..max_relayers as u64 {
+			assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), 100 + relayer));
+		}
+		assert_noop!(
+			Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_A),
+			Error::<Test>::TooManyRelayers
+		);
+
+		// room is made by removing a relayer
+		assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), 100));
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_A));
+	})
+}
+
+#[test]
+fn instances_are_independent() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Bridge::account_id(), BridgePalletId::get().into_account_truncating());
+		assert_eq!(
+			SecondBridge::account_id(),
+			SecondBridgePalletId::get().into_account_truncating()
+		);
+		assert_ne!(Bridge::account_id(), SecondBridge::account_id());
+
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_A));
+		assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), 0));
+		assert_ok!(SecondBridge::set_threshold(RuntimeOrigin::root(), TEST_THRESHOLD));
+
+		assert!(Bridge::is_relayer(&RELAYER_A));
+		assert!(!SecondBridge::is_relayer(&RELAYER_A));
+		assert!(Bridge::chain_whitelisted(0));
+		assert!(!SecondBridge::chain_whitelisted(0));
+		assert_eq!(Bridge::relayer_threshold(), 1);
+		assert_eq!(SecondBridge::relayer_threshold(), TEST_THRESHOLD);
+
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::RelayerAdded(RELAYER_A)),
+			RuntimeEvent::Bridge(PalletEvent::ChainWhitelisted(0)),
+			RuntimeEvent::SecondBridge(PalletEvent::RelayerThresholdChanged(TEST_THRESHOLD)),
+		]);
+	})
+}
+
 fn make_proposal(remark: Vec<u8>) -> RuntimeCall {
 	RuntimeCall::System(system::Call::remark { remark })
 }
@@ -472,3 +522,439 @@ fn transfer_fungible_no_whitelist() {
 		);
 	})
 }
+
+fn eth_signer(seed: u8) -> (ecdsa::Pair, H160) {
+	let pair = ecdsa::Pair::from_seed(&[seed; 32]);
+	let digest = [0u8; 32];
+	let pubkey =
+		sp_io::crypto::secp256k1_ecdsa_recover(&pair.sign_prehashed(&digest).0, &digest).unwrap();
+	(pair, H160::from_slice(&hashing::keccak_256(&pubkey)[12..]))
+}
+
+/// Signature of `pair` proving its ownership to `relayer`
+fn signer_proof(relayer: u64, pair: &ecdsa::Pair) -> ecdsa::Signature {
+	pair.sign_prehashed(&hashing::keccak_256(&Bridge::relayer_signer_message(&relayer)))
+}
+
+#[test]
+fn merkle_proofs_verify() {
+	let leaves: Vec<[u8; 32]> = (0u8..5).map(|i| hashing::keccak_256(&[i])).collect();
+	let root = hashing::merkle_root(&leaves);
+	for (i, leaf) in leaves.iter().enumerate() {
+		let proof = hashing::merkle_proof(&leaves, i).unwrap();
+		assert!(hashing::verify_merkle_proof(&root, *leaf, &proof));
+	}
+	assert!(hashing::merkle_proof(&leaves, 5).is_none());
+	assert!(!hashing::verify_merkle_proof(&root, hashing::keccak_256(&[9]), &[]));
+	assert_eq!(hashing::merkle_root(&[]), [0u8; 32]);
+	assert_eq!(hashing::merkle_root(&leaves[..1]), leaves[0]);
+}
+
+#[test]
+fn relayer_set_rotation() {
+	new_test_ext().execute_with(|| {
+		let (pair_a, signer_a) = eth_signer(1);
+		let (pair_b, signer_b) = eth_signer(2);
+		let (pair_c, signer_c) = eth_signer(3);
+		let (pair_x, _) = eth_signer(4);
+
+		assert_eq!(Bridge::relayer_set_id(), 0);
+		assert_ok!(Bridge::set_threshold(RuntimeOrigin::root(), TEST_THRESHOLD));
+		assert_eq!(Bridge::relayer_set_id(), 1);
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_A));
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_B));
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_C));
+		// no signing key registered, the set is unchanged
+		assert_eq!(Bridge::relayer_set_id(), 1);
+
+		assert_noop!(
+			Bridge::set_relayer_signer(
+				RuntimeOrigin::signed(1),
+				signer_a,
+				signer_proof(1, &pair_a)
+			),
+			Error::<Test>::MustBeRelayer
+		);
+		// the key must sign for the relayer registering it
+		assert_noop!(
+			Bridge::set_relayer_signer(
+				RuntimeOrigin::signed(RELAYER_A),
+				signer_a,
+				signer_proof(RELAYER_A, &pair_x)
+			),
+			Error::<Test>::InvalidSignature
+		);
+		assert_noop!(
+			Bridge::set_relayer_signer(
+				RuntimeOrigin::signed(RELAYER_A),
+				signer_a,
+				signer_proof(RELAYER_B, &pair_a)
+			),
+			Error::<Test>::InvalidSignature
+		);
+		let proof_a = signer_proof(RELAYER_A, &pair_a);
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_A),
+			signer_a,
+			proof_a.clone()
+		));
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_B),
+			signer_b,
+			signer_proof(RELAYER_B, &pair_b)
+		));
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_C),
+			signer_c,
+			signer_proof(RELAYER_C, &pair_c)
+		));
+		// the proof is bound to the set id, it can't be replayed after a rotation
+		assert_noop!(
+			Bridge::set_relayer_signer(RuntimeOrigin::signed(RELAYER_A), signer_a, proof_a),
+			Error::<Test>::InvalidSignature
+		);
+		assert_eq!(Bridge::relayer_set_id(), 4);
+		let mut signers = vec![signer_a, signer_b, signer_c];
+		signers.sort();
+		assert_eq!(
+			Bridge::relayer_sets(4),
+			Some(RelayerSet { signers, threshold: TEST_THRESHOLD })
+		);
+
+		// removing a relayer rotates into set 5, which set 4 has to sign
+		assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), RELAYER_C));
+		assert_eq!(Bridge::relayer_set_id(), 5);
+		let new_set = Bridge::relayer_sets(5).unwrap();
+		assert_eq!(new_set.signers.len(), 2);
+		let message = Bridge::relayer_set_change_message(5, &new_set);
+		let message_hash = hashing::keccak_256(&message);
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::RelayerSetChanged(
+			5,
+			H256(message_hash),
+		))]);
+
+		assert_noop!(
+			Bridge::submit_relayer_set_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				5,
+				pair_x.sign_prehashed(&message_hash)
+			),
+			Error::<Test>::SignerNotInRelayerSet
+		);
+		assert_noop!(
+			Bridge::submit_relayer_set_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				6,
+				pair_a.sign_prehashed(&message_hash)
+			),
+			Error::<Test>::RelayerSetNotFound
+		);
+		assert_ok!(Bridge::submit_relayer_set_signature(
+			RuntimeOrigin::signed(RELAYER_A),
+			5,
+			pair_a.sign_prehashed(&message_hash)
+		));
+		assert_noop!(
+			Bridge::submit_relayer_set_signature(
+				RuntimeOrigin::signed(RELAYER_B),
+				5,
+				pair_a.sign_prehashed(&message_hash)
+			),
+			Error::<Test>::SignerAlreadySigned
+		);
+		assert!(Bridge::relayer_set_change_proof(5).is_none());
+
+		assert_ok!(Bridge::submit_relayer_set_signature(
+			RuntimeOrigin::signed(RELAYER_B),
+			5,
+			pair_b.sign_prehashed(&message_hash)
+		));
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::RelayerSetSignatureSubmitted(5, signer_b)),
+			RuntimeEvent::Bridge(PalletEvent::RelayerSetChangeSigned(5)),
+		]);
+
+		let proof = Bridge::relayer_set_change_proof(5).unwrap();
+		assert_eq!(proof.message, message);
+		assert_eq!(proof.message_hash, H256(message_hash));
+		assert_eq!(proof.relayer_set, new_set);
+		assert_eq!(proof.outgoing_root, Bridge::relayer_sets(4).unwrap().root());
+		assert_eq!(proof.signatures.len(), 2);
+		for ((signer, signature), merkle_proof) in proof.signatures.iter().zip(proof.proofs.iter())
+		{
+			assert_eq!(Bridge::recover_signer(signature, &message_hash), Some(*signer));
+			let path: Vec<[u8; 32]> = merkle_proof.iter().map(|h| h.0).collect();
+			assert!(hashing::verify_merkle_proof(
+				&proof.outgoing_root.0,
+				hashing::keccak_256(signer.as_bytes()),
+				&path
+			));
+		}
+	})
+}
+
+#[test]
+fn withdrawal_signatures() {
+	let dest_id = 2;
+	let resource_id = derive_resource_id(dest_id, b"hash");
+	let recipient = vec![0x11; 20];
+	new_test_ext_initialized(dest_id, resource_id).execute_with(|| {
+		let (pair_a, signer_a) = eth_signer(1);
+		let (pair_b, signer_b) = eth_signer(2);
+		let (pair_c, signer_c) = eth_signer(3);
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_A),
+			signer_a,
+			signer_proof(RELAYER_A, &pair_a)
+		));
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_B),
+			signer_b,
+			signer_proof(RELAYER_B, &pair_b)
+		));
+
+		assert_ok!(Bridge::signal_transfer_fungible(dest_id, resource_id, recipient.clone(), 100));
+		let withdrawal = Bridge::withdrawals(dest_id, 1).unwrap();
+		assert_eq!(
+			withdrawal,
+			OutboundWithdrawal { resource_id, amount: 100, recipient, payload: None }
+		);
+		let digest = Bridge::withdrawal_digest(dest_id, 1, &withdrawal);
+
+		assert_noop!(
+			Bridge::submit_withdrawal_signature(
+				RuntimeOrigin::signed(RELAYER_C),
+				dest_id,
+				1,
+				pair_c.sign_prehashed(&digest)
+			),
+			Error::<Test>::RelayerSignerNotSet
+		);
+		assert_noop!(
+			Bridge::submit_withdrawal_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				dest_id,
+				2,
+				pair_a.sign_prehashed(&digest)
+			),
+			Error::<Test>::WithdrawalNotFound
+		);
+		assert_noop!(
+			Bridge::submit_withdrawal_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				dest_id,
+				1,
+				pair_b.sign_prehashed(&digest)
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		// recovery ids other than {0, 1} and {27, 28} can't be packed for the contract
+		let mut signature = pair_a.sign_prehashed(&digest);
+		signature.0[64] += 2;
+		assert_noop!(
+			Bridge::submit_withdrawal_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				dest_id,
+				1,
+				signature
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		let mut signature = pair_a.sign_prehashed(&digest);
+		signature.0[64] += 27;
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_A),
+			dest_id,
+			1,
+			signature
+		));
+		assert_noop!(
+			Bridge::submit_withdrawal_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				dest_id,
+				1,
+				pair_a.sign_prehashed(&digest)
+			),
+			Error::<Test>::SignerAlreadySigned
+		);
+		assert!(Bridge::packed_withdrawal_signatures(dest_id, 1).is_none());
+
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_B),
+			dest_id,
+			1,
+			pair_b.sign_prehashed(&digest)
+		));
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::WithdrawalSignatureSubmitted(dest_id, 1, signer_b)),
+			RuntimeEvent::Bridge(PalletEvent::WithdrawalSigned(dest_id, 1, H256(digest))),
+		]);
+
+		let packed = Bridge::packed_withdrawal_signatures(dest_id, 1).unwrap();
+		assert_eq!(packed.len(), 2 * 65);
+		let mut expected = vec![signer_a, signer_b];
+		expected.sort();
+		for (chunk, signer) in packed.chunks(65).zip(expected) {
+			assert!(chunk[64] == 27 || chunk[64] == 28);
+			let signature = ecdsa::Signature::from_slice(chunk).unwrap();
+			assert_eq!(Bridge::recover_signer(&signature, &digest), Some(signer));
+		}
+	})
+}
+
+#[test]
+fn withdrawal_signatures_of_current_relayers_only() {
+	let dest_id = 2;
+	let resource_id = derive_resource_id(dest_id, b"hash");
+	new_test_ext_initialized(dest_id, resource_id).execute_with(|| {
+		let (pair_a, signer_a) = eth_signer(1);
+		let (pair_b, signer_b) = eth_signer(2);
+		let (pair_c, signer_c) = eth_signer(3);
+		for (relayer, pair, signer) in
+			[(RELAYER_A, &pair_a, signer_a), (RELAYER_B, &pair_b, signer_b)]
+		{
+			assert_ok!(Bridge::set_relayer_signer(
+				RuntimeOrigin::signed(relayer),
+				signer,
+				signer_proof(relayer, pair)
+			));
+		}
+
+		assert_ok!(Bridge::signal_transfer_fungible(dest_id, resource_id, vec![0x11; 20], 100));
+		let digest =
+			Bridge::withdrawal_digest(dest_id, 1, &Bridge::withdrawals(dest_id, 1).unwrap());
+		// the recovery id is accepted as {27, 28} as well
+		let mut signature_a = pair_a.sign_prehashed(&digest);
+		signature_a.0[64] += 27;
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_A),
+			dest_id,
+			1,
+			signature_a
+		));
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_B),
+			dest_id,
+			1,
+			pair_b.sign_prehashed(&digest)
+		));
+		let packed = Bridge::packed_withdrawal_signatures(dest_id, 1).unwrap();
+		assert!(packed.chunks(65).all(|chunk| chunk[64] == 27 || chunk[64] == 28));
+
+		// the signature of a removed relayer doesn't count anymore
+		assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), RELAYER_B));
+		assert_eq!(Bridge::current_withdrawal_signatures(dest_id, 1).len(), 1);
+		assert!(Bridge::packed_withdrawal_signatures(dest_id, 1).is_none());
+
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_C),
+			signer_c,
+			signer_proof(RELAYER_C, &pair_c)
+		));
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_C),
+			dest_id,
+			1,
+			pair_c.sign_prehashed(&digest)
+		));
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::WithdrawalSignatureSubmitted(dest_id, 1, signer_c)),
+			RuntimeEvent::Bridge(PalletEvent::WithdrawalSigned(dest_id, 1, H256(digest))),
+		]);
+		let packed = Bridge::packed_withdrawal_signatures(dest_id, 1).unwrap();
+		let mut expected = vec![signer_a, signer_c];
+		expected.sort();
+		for (chunk, signer) in packed.chunks(65).zip(expected) {
+			let signature = ecdsa::Signature::from_slice(chunk).unwrap();
+			assert_eq!(Bridge::recover_signer(&signature, &digest), Some(signer));
+		}
+	})
+}
+
+#[test]
+fn withdrawal_with_call_can_be_signed() {
+	let dest_id = 2;
+	let resource_id = derive_resource_id(dest_id, b"hash");
+	let recipient = vec![0x11; 20];
+	new_test_ext_initialized(dest_id, resource_id).execute_with(|| {
+		let (pair_a, signer_a) = eth_signer(1);
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_A),
+			signer_a,
+			signer_proof(RELAYER_A, &pair_a)
+		));
+
+		assert_ok!(Bridge::signal_transfer_fungible_with_call(
+			dest_id,
+			resource_id,
+			recipient.clone(),
+			100,
+			vec![0xab; 4]
+		));
+		let withdrawal = Bridge::withdrawals(dest_id, 1).unwrap();
+		assert_eq!(withdrawal.payload, Some(vec![0xab; 4]));
+		// the payload is part of the signed digest
+		let digest = Bridge::withdrawal_digest(dest_id, 1, &withdrawal);
+		assert_ne!(
+			digest,
+			Bridge::withdrawal_digest(
+				dest_id,
+				1,
+				&OutboundWithdrawal { payload: None, ..withdrawal.clone() }
+			)
+		);
+
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_A),
+			dest_id,
+			1,
+			pair_a.sign_prehashed(&digest)
+		));
+		assert_eq!(Bridge::withdrawal_signatures(dest_id, 1).len(), 1);
+	})
+}
+
+#[test]
+fn expired_withdrawals_are_pruned() {
+	let dest_id = 2;
+	let resource_id = derive_resource_id(dest_id, b"hash");
+	new_test_ext_initialized(dest_id, resource_id).execute_with(|| {
+		let (pair_a, signer_a) = eth_signer(1);
+		assert_ok!(Bridge::set_relayer_signer(
+			RuntimeOrigin::signed(RELAYER_A),
+			signer_a,
+			signer_proof(RELAYER_A, &pair_a)
+		));
+		assert_ok!(Bridge::signal_transfer_fungible(dest_id, resource_id, vec![0x11; 20], 100));
+		let digest =
+			Bridge::withdrawal_digest(dest_id, 1, &Bridge::withdrawals(dest_id, 1).unwrap());
+		assert_ok!(Bridge::submit_withdrawal_signature(
+			RuntimeOrigin::signed(RELAYER_A),
+			dest_id,
+			1,
+			pair_a.sign_prehashed(&digest)
+		));
+
+		let expiry = 1 + ProposalLifetime::get();
+		assert_eq!(Bridge::withdrawal_expiries(expiry), vec![(dest_id, 1)]);
+		Bridge::on_initialize(expiry - 1);
+		assert!(Bridge::withdrawals(dest_id, 1).is_some());
+
+		System::set_block_number(expiry);
+		Bridge::on_initialize(expiry);
+		assert!(Bridge::withdrawals(dest_id, 1).is_none());
+		assert!(Bridge::withdrawal_signatures(dest_id, 1).is_empty());
+		assert!(Bridge::withdrawal_expiries(expiry).is_empty());
+		assert_noop!(
+			Bridge::submit_withdrawal_signature(
+				RuntimeOrigin::signed(RELAYER_A),
+				dest_id,
+				1,
+				pair_a.sign_prehashed(&digest)
+			),
+			Error::<Test>::WithdrawalNotFound
+		);
+	})
+}
@@ -14,27 +14,26 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_bridge
+//! Weights for pallet_bridge
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-10-09, STEPS: `20`, REPEAT: 50, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("litentry-dev"), DB CACHE: 20
+//! NOT GENERATED BY THE BENCHMARK CLI YET: the relayer set rotation and the withdrawal
+//! signatures made the relayer calls linear in the number of relayers `r`, up to `MaxRelayers`.
+//! The storage accesses follow the code and the times are estimates until this file is
+//! regenerated on reference hardware, with `./scripts/benchmark-weights.sh`, which runs:
 
-// Executed Command:
-// ./target/release/litentry-collator
+// Command:
+// ./target/release/canbus
 // benchmark
 // pallet
-// --chain=litentry-dev
-// --execution=wasm
-// --db-cache=20
+// --chain=dev
 // --wasm-execution=compiled
 // --pallet=pallet_bridge
 // --extrinsic=*
 // --heap-pages=4096
-// --steps=20
-// --repeat=50
-// --header=./LICENSE_HEADER
-// --template=./templates/benchmark/pallet-weight-template.hbs
+// --steps=50
+// --repeat=20
+// --header=./.maintain/header.txt
+// --template=./.maintain/frame-weight-template.hbs
 // --output=./pallets/bridge/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -47,22 +46,32 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_bridge.
 pub trait WeightInfo {
-	fn set_threshold() -> Weight;
+	fn set_threshold(r: u32, ) -> Weight;
 	fn whitelist_chain() -> Weight;
-	fn add_relayer() -> Weight;
-	fn remove_relayer() -> Weight;
+	fn add_relayer(r: u32, ) -> Weight;
+	fn remove_relayer(r: u32, ) -> Weight;
 	fn acknowledge_proposal() -> Weight;
 	fn reject_proposal() -> Weight;
 	fn eval_vote_state() -> Weight;
+	fn set_relayer_signer(r: u32, ) -> Weight;
+	fn submit_relayer_set_signature(r: u32, ) -> Weight;
+	fn submit_withdrawal_signature(r: u32, ) -> Weight;
 }
 
 /// Weights for pallet_bridge using the Litentry node and recommended hardware.
 pub struct LitentryWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
-	// Storage: ChainBridge RelayerThreshold (r:0 w:1)
-	fn set_threshold() -> Weight {
-		Weight::from_parts(12_574_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	// Storage: ChainBridge RelayerThreshold (r:1 w:1)
+	// Storage: ChainBridge RelayerSigners (r:r w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn set_threshold(r: u32, ) -> Weight {
+		Weight::from_parts(14_126_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: ChainBridge ChainNonces (r:1 w:1)
 	fn whitelist_chain() -> Weight {
@@ -72,17 +81,31 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 	}
 	// Storage: ChainBridge Relayers (r:1 w:1)
 	// Storage: ChainBridge RelayerCount (r:1 w:1)
-	fn add_relayer() -> Weight {
-		Weight::from_parts(17_723_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	// Storage: ChainBridge RelayerSigners (r:r w:0)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn add_relayer(r: u32, ) -> Weight {
+		Weight::from_parts(19_517_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: ChainBridge Relayers (r:1 w:1)
 	// Storage: ChainBridge RelayerCount (r:1 w:1)
-	fn remove_relayer() -> Weight {
-		Weight::from_parts(18_956_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	// Storage: ChainBridge RelayerSigners (r:r w:1)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn remove_relayer(r: u32, ) -> Weight {
+		Weight::from_parts(21_038_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: ChainBridge Relayers (r:1 w:0)
 	// Storage: ChainBridge ChainNonces (r:1 w:0)
@@ -114,14 +137,57 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: ChainBridge Relayers (r:1 w:0)
+	// Storage: ChainBridge RelayerSigners (r:r w:1)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn set_relayer_signer(r: u32, ) -> Weight {
+		Weight::from_parts(68_412_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge RelayerSetSignatures (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn submit_relayer_set_signature(r: u32, ) -> Weight {
+		Weight::from_parts(71_630_000 as u64, 0)
+			.saturating_add(Weight::from_parts(1_382_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:0)
+	// Storage: ChainBridge RelayerSigners (r:1 w:0)
+	// Storage: ChainBridge Withdrawals (r:1 w:0)
+	// Storage: ChainBridge WithdrawalSignatures (r:1 w:1)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:0)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	/// The range of component `r` is `[1, 32]`.
+	fn submit_withdrawal_signature(r: u32, ) -> Weight {
+		Weight::from_parts(74_105_000 as u64, 0)
+			.saturating_add(Weight::from_parts(1_297_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	// Storage: ChainBridge RelayerThreshold (r:0 w:1)
-	fn set_threshold() -> Weight {
-		Weight::from_parts(12_574_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	// Storage: ChainBridge RelayerThreshold (r:1 w:1)
+	// Storage: ChainBridge RelayerSigners (r:r w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn set_threshold(r: u32, ) -> Weight {
+		Weight::from_parts(14_126_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	// Storage: ChainBridge ChainNonces (r:1 w:1)
 	fn whitelist_chain() -> Weight {
@@ -131,17 +197,31 @@ impl WeightInfo for () {
 	}
 	// Storage: ChainBridge Relayers (r:1 w:1)
 	// Storage: ChainBridge RelayerCount (r:1 w:1)
-	fn add_relayer() -> Weight {
-		Weight::from_parts(17_723_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	// Storage: ChainBridge RelayerSigners (r:r w:0)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn add_relayer(r: u32, ) -> Weight {
+		Weight::from_parts(19_517_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	// Storage: ChainBridge Relayers (r:1 w:1)
 	// Storage: ChainBridge RelayerCount (r:1 w:1)
-	fn remove_relayer() -> Weight {
-		Weight::from_parts(18_956_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	// Storage: ChainBridge RelayerSigners (r:r w:1)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn remove_relayer(r: u32, ) -> Weight {
+		Weight::from_parts(21_038_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 	// Storage: ChainBridge Relayers (r:1 w:0)
 	// Storage: ChainBridge ChainNonces (r:1 w:0)
@@ -173,4 +253,40 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: ChainBridge Relayers (r:1 w:0)
+	// Storage: ChainBridge RelayerSigners (r:r w:1)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn set_relayer_signer(r: u32, ) -> Weight {
+		Weight::from_parts(68_412_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_104_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge RelayerSetSignatures (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn submit_relayer_set_signature(r: u32, ) -> Weight {
+		Weight::from_parts(71_630_000 as u64, 0)
+			.saturating_add(Weight::from_parts(1_382_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:0)
+	// Storage: ChainBridge RelayerSigners (r:1 w:0)
+	// Storage: ChainBridge Withdrawals (r:1 w:0)
+	// Storage: ChainBridge WithdrawalSignatures (r:1 w:1)
+	// Storage: ChainBridge CurrentRelayerSetId (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:0)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	/// The range of component `r` is `[1, 32]`.
+	fn submit_withdrawal_signature(r: u32, ) -> Weight {
+		Weight::from_parts(74_105_000 as u64, 0)
+			.saturating_add(Weight::from_parts(1_297_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
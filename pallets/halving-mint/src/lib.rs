@@ -2,26 +2,44 @@
 //!
 //! This pallet mints the (native) token in a halving way.
 //!
-//! It will be parameterized with the total issuance count and halving interval (in blocks),
-//! The minted token is deposited to the `beneficiary` account, which should be a privated
-//! account derived from the PalletId(similar to treasury). There's a trait `OnTokenMinted`
-//! to hook the callback into other pallet.
+//! It will be parameterized with an `EmissionCurve` that tells how many tokens are minted per
+//! block. Besides the original halving curve, a linearly decaying curve and a piecewise table
+//! supplied by the `ManagerOrigin` are provided. The minted token is deposited to the
+//! `beneficiary` account, which should be a privated account derived from the PalletId(similar
+//! to treasury), or split among the `Beneficiaries` set by the `ManagerOrigin`, the author of
+//! the block being one of the options. There's a trait `OnTokenMinted` to hook the callback into
+//! other pallet, it's called for each beneficiary.
+//!
+//! To save the per-block overhead, the tokens of the accrued blocks are minted at once every
+//! `MintPeriod` blocks, or when anyone calls `settle`. Only the share of the block author is
+//! minted in each block, so that every author gets the reward of its own block, and added to
+//! the author's running total in `AuthorEarnings`.
 //!
 //! The main parameters:
 //! - total issuance
-//! - halving interval
+//! - emission curve
 //! - beneficiary account
 //! are defined as runtime constants. It implies that once onboarded, they can be changed
 //! only by runtime upgrade. Thus it has a stronger guarantee in comparison to extrinsics.
+//! The piecewise table is the exception, it's meant for curves that governance adjusts.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::too_many_arguments)]
 
-use codec::{Decode, Encode};
-use frame_support::traits::Currency;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::{BoundedVec, Weight},
+	traits::{Currency, Get},
+	PalletId,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{SaturatedConversion, Saturating, Zero},
+	Permill,
+};
 
 #[cfg(test)]
 mod mock;
@@ -29,12 +47,39 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+pub mod runtime_api;
+
 mod traits;
-pub use traits::OnTokenMinted;
+use traits::mul_rational;
+pub use traits::{
+	EmissionCurve, Halving, LinearDecay, OnTokenMinted, TimeEmissionCurve, TimeHalving,
+};
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type EmissionScheduleOf<T> =
+	BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), <T as Config>::MaxEmissionSteps>;
+
+pub type BeneficiariesOf<T> = BoundedVec<
+	(Beneficiary<<T as frame_system::Config>::AccountId>, Permill),
+	<T as Config>::MaxBeneficiaries,
+>;
+
+/// A recipient of minted tokens
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub enum Beneficiary<AccountId> {
+	#[codec(index = 0)]
+	Account(AccountId),
+	/// The sovereign account of a pallet
+	#[codec(index = 1)]
+	Pallet(PalletId),
+	/// The author of the block being minted
+	#[codec(index = 2)]
+	BlockAuthor,
+}
+
 /// an on/off flag, used in both `MintState` and `OnTokenMintedState`
 #[derive(
 	PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize, Encode, Decode, Debug, TypeInfo,
@@ -47,21 +92,37 @@ pub enum State {
 	Running,
 }
 
+/// What the emission is derived from
+#[derive(
+	PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize, Encode, Decode, Debug, TypeInfo,
+)]
+pub enum EmissionMode {
+	/// `EmissionCurve` over the running blocks
+	#[default]
+	#[codec(index = 0)]
+	Blocks,
+	/// `TimeEmissionCurve` over the running (wall-clock) time, each block mints the emission
+	/// of the time elapsed since the previous block
+	#[codec(index = 1)]
+	Timestamp,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{ReservableCurrency, StorageVersion},
+		traits::{FindAuthor, Imbalance, ReservableCurrency, StorageVersion, UnixTime},
 		PalletId,
 	};
 	use frame_system::pallet_prelude::{BlockNumberFor, *};
 	use sp_runtime::{
-		traits::{AccountIdConversion, One, Zero},
+		traits::{AccountIdConversion, One, SaturatedConversion, Zero},
 		Saturating,
 	};
+	use sp_std::{vec, vec::Vec};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -74,18 +135,38 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The origin to control the minting configuration
 		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
-		/// The total issuance of the (native) token
+		/// The total issuance of the (native) token, a hard cap on what this pallet mints
 		#[pallet::constant]
 		type TotalIssuance: Get<BalanceOf<Self>>;
-		/// Halving internal in blocks, we force u32 type, BlockNumberFor<T> implements
-		/// AtLeast32BitUnsigned so it's safe
+		/// The per-block emission schedule
+		type EmissionCurve: EmissionCurve<BlockNumberFor<Self>, BalanceOf<Self>>;
+		/// Maximum number of steps in the `EmissionSchedule` used by `PiecewiseEmission`
 		#[pallet::constant]
-		type HalvingInterval: Get<u32>;
-		/// The beneficiary PalletId, used for deriving its sovereign AccountId
+		type MaxEmissionSteps: Get<u32>;
+		/// The beneficiary PalletId, used for deriving its sovereign AccountId. It receives all
+		/// the minted tokens as long as no `Beneficiaries` are set
 		#[pallet::constant]
 		type BeneficiaryId: Get<PalletId>;
+		/// Maximum number of `Beneficiaries`
+		#[pallet::constant]
+		type MaxBeneficiaries: Get<u32>;
 		/// Hook for other pallets to deal with OnTokenMinted event
 		type OnTokenMinted: OnTokenMinted<Self::AccountId, BalanceOf<Self>>;
+		/// Finds the author of the current block for the `BlockAuthor` beneficiary
+		type FindAuthor: FindAuthor<Self::AccountId>;
+		/// The minting happens once every `MintPeriod` (running) blocks, for all the blocks
+		/// accrued since the last minting. The `BlockAuthor` share is minted to the author of
+		/// each block right away
+		#[pallet::constant]
+		type MintPeriod: Get<BlockNumberFor<Self>>;
+		/// The wall-clock time of the `Timestamp` emission mode
+		type UnixTime: UnixTime;
+		/// The emission schedule of the `Timestamp` emission mode
+		type TimeEmissionCurve: TimeEmissionCurve<BalanceOf<Self>>;
+		/// Expected block time in milliseconds, to project the emission of future blocks and to
+		/// carry over the progress of the `Blocks` emission mode into the `Timestamp` one
+		#[pallet::constant]
+		type ExpectedBlockTime: Get<u64>;
 	}
 
 	#[pallet::event]
@@ -95,6 +176,10 @@ pub mod pallet {
 		OnTokenMintedStateChanged { new_state: State },
 		MintStarted { start_block: BlockNumberFor<T> },
 		Minted { to: T::AccountId, amount: BalanceOf<T> },
+		EmissionScheduleSet { schedule: EmissionScheduleOf<T> },
+		MintCompleted { total_minted: BalanceOf<T> },
+		BeneficiariesSet { beneficiaries: BeneficiariesOf<T> },
+		AuthorRewarded { author: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -105,6 +190,14 @@ pub mod pallet {
 		MintNotStarted,
 		StartBlockTooEarly,
 		SkippedBlocksOverflow,
+		/// The steps of the emission schedule aren't strictly ascending
+		EmissionScheduleUnsorted,
+		/// The splits of the beneficiaries don't sum up to 100%
+		InvalidBeneficiarySplits,
+		/// The minting is stopped
+		MintStopped,
+		/// No blocks accrued since the last minting
+		NothingToSettle,
 	}
 
 	#[pallet::storage]
@@ -127,11 +220,69 @@ pub mod pallet {
 	#[pallet::getter(fn skipped_blocks)]
 	pub type SkippedBlocks<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+	/// Tokens minted by this pallet up to the last settlement, never exceeds `TotalIssuance`.
+	/// The `BlockAuthor` shares minted in between are counted by the next settlement
+	#[pallet::storage]
+	#[pallet::getter(fn total_minted)]
+	pub type TotalMinted<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Number of (running) blocks whose emission has been minted, counted from the start block
+	#[pallet::storage]
+	#[pallet::getter(fn settled_blocks)]
+	pub type SettledBlocks<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// What the emission is derived from, blocks or wall-clock time
+	#[pallet::storage]
+	#[pallet::getter(fn emission_mode)]
+	pub type CurrentEmissionMode<T: Config> = StorageValue<_, EmissionMode, ValueQuery>;
+
+	/// The moment (in milliseconds) the minting started in the `Timestamp` emission mode,
+	/// postponed by the time it was stopped
+	#[pallet::storage]
+	#[pallet::getter(fn effective_start_time)]
+	pub type EffectiveStartTime<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// The moment (in milliseconds) the minting was stopped in the `Timestamp` emission mode
+	#[pallet::storage]
+	#[pallet::getter(fn stopped_at)]
+	pub type StoppedAt<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// Running time (in milliseconds) whose emission has been minted in the `Timestamp`
+	/// emission mode
+	#[pallet::storage]
+	#[pallet::getter(fn settled_time)]
+	pub type SettledTime<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The piecewise emission table used by `PiecewiseEmission`, each step is a pair of
+	/// (elapsed blocks since the start, tokens minted per block from then on)
+	#[pallet::storage]
+	#[pallet::getter(fn emission_schedule)]
+	pub type EmissionSchedule<T: Config> = StorageValue<_, EmissionScheduleOf<T>, ValueQuery>;
+
+	/// The beneficiaries and their share of the minted tokens, `BeneficiaryId` gets everything
+	/// if empty
+	#[pallet::storage]
+	#[pallet::getter(fn beneficiaries)]
+	pub type Beneficiaries<T: Config> = StorageValue<_, BeneficiariesOf<T>, ValueQuery>;
+
+	/// Running time (in milliseconds) whose `BlockAuthor` share has been minted in the
+	/// `Timestamp` emission mode
+	#[pallet::storage]
+	#[pallet::getter(fn author_rewarded_time)]
+	pub type AuthorRewardedTime<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The `BlockAuthor` shares each author earned so far
+	#[pallet::storage]
+	#[pallet::getter(fn author_earnings)]
+	pub type AuthorEarnings<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub mint_state: State,
 		pub on_token_minted_state: State,
 		pub start_block: Option<BlockNumberFor<T>>,
+		pub emission_mode: EmissionMode,
 	}
 
 	impl<T: Config> Default for GenesisConfig<T> {
@@ -140,6 +291,7 @@ pub mod pallet {
 				mint_state: State::Stopped,
 				on_token_minted_state: State::Stopped,
 				start_block: None,
+				emission_mode: EmissionMode::Blocks,
 			}
 		}
 	}
@@ -152,6 +304,7 @@ pub mod pallet {
 			if let Some(n) = self.start_block {
 				StartBlock::<T>::put(n);
 			}
+			CurrentEmissionMode::<T>::put(self.emission_mode);
 		}
 	}
 
@@ -169,48 +322,28 @@ pub mod pallet {
 						return weight;
 					}
 
-					let halving_interval = T::HalvingInterval::get();
-
-					// calculate the amount of initially minted tokens before first halving
-					let mut minted = T::TotalIssuance::get() / (halving_interval * 2).into();
-					// halving round index
-					let halving_round = (now - start_block.saturating_add(skipped_blocks)) /
-						halving_interval.into();
-					// beneficiary account
-					let to = Self::beneficiary_account();
-
-					// 2 reads: `total_issuance`, `halving_interval`
-					weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 0));
-
-					// if we want to use bit shift, we need to:
-					//   1. know the overlfow limit similar to what bitcoin has: `if (halvings >=
-					//      64) return 0;` so 127 for u128
-					//   2. coerce the `halving_round` to u32
-					// but both `halving_round` and `minted` are associated types that need to be
-					// defined during runtime binding thus plain division is used
-					let mut i = BlockNumberFor::<T>::zero();
-					while i < halving_round {
-						minted = minted / 2u32.into();
-						i += BlockNumberFor::<T>::one();
+					// the running time starts with the timestamp of the previous block, so that
+					// the time of the start block counts as well
+					if Self::emission_mode() == EmissionMode::Timestamp &&
+						Self::effective_start_time().is_none()
+					{
+						EffectiveStartTime::<T>::put(Self::now_millis());
+						// 1 write: `effective_start_time`
+						weight = weight.saturating_add(T::DbWeight::get().reads_writes(0, 1));
 					}
+					// 2 reads: `emission_mode`, `effective_start_time`
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 0));
 
-					// theoreticlaly we can deal with the minted tokens directly in the trait impl
-					// pallet, without depositing to an account first.
-					// but the purpose of having the extra logic is to make sure the tokens are
-					// minted to the beneficiary account, regardless of what happens callback. Even
-					// if the callback errors out, it's guaranteed that the tokens are
-					// already minted (and stored on an account), which resonates with the "fair
-					// launch" concept.
-					//
-					// Also imagine there's no callback impl, in this case the tokens will still be
-					// minted and accumulated.
-					let _ = T::Currency::deposit_creating(&to, minted);
-					Self::deposit_event(Event::Minted { to: to.clone(), amount: minted });
-					if Self::on_token_minted_state() == State::Running {
-						weight = weight.saturating_add(T::OnTokenMinted::token_minted(to, minted));
-					}
-					// 1 read: `on_token_minted_state`
+					let accrued_blocks =
+						Self::accrued_blocks(now, start_block.saturating_add(skipped_blocks));
+					// 1 read: `settled_blocks`
 					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 0));
+					weight = weight.saturating_add(Self::reward_author(accrued_blocks));
+					if accrued_blocks.saturating_sub(Self::settled_blocks()) >=
+						T::MintPeriod::get().max(One::one())
+					{
+						weight = weight.saturating_add(Self::do_settle(accrued_blocks));
+					}
 				} else {
 					// we should have minted tokens but it's forcibly stopped
 					let skipped_blocks =
@@ -222,6 +355,11 @@ pub mod pallet {
 			}
 			weight
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	// TODO: benchmarking and WeightInfo
@@ -236,6 +374,22 @@ pub mod pallet {
 			T::ManagerOrigin::ensure_origin(origin)?;
 			ensure!(StartBlock::<T>::get().is_some(), Error::<T>::MintNotStarted);
 			ensure!(state != Self::mint_state(), Error::<T>::MintStateUnchanged);
+			// the stopped time doesn't count as running time
+			if Self::effective_start_time().is_some() {
+				match state {
+					State::Stopped => StoppedAt::<T>::put(Self::now_millis()),
+					State::Running =>
+						if let Some(stopped_at) = StoppedAt::<T>::take() {
+							EffectiveStartTime::<T>::mutate(|start| {
+								*start = start.map(|start| {
+									start.saturating_add(
+										Self::now_millis().saturating_sub(stopped_at),
+									)
+								})
+							});
+						},
+				}
+			}
 			MintState::<T>::put(state);
 			Self::deposit_event(Event::MintStateChanged { new_state: state });
 			Ok(Pays::No.into())
@@ -289,11 +443,476 @@ pub mod pallet {
 			Self::deposit_event(Event::MintStarted { start_block });
 			Ok(Pays::No.into())
 		}
+
+		/// Set the piecewise emission table, only takes effect if `PiecewiseEmission` is
+		/// configured as `EmissionCurve`
+		#[pallet::call_index(4)]
+		#[pallet::weight((195_000_000, DispatchClass::Normal))]
+		pub fn set_emission_schedule(
+			origin: OriginFor<T>,
+			schedule: EmissionScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				schedule.windows(2).all(|w| w[0].0 < w[1].0),
+				Error::<T>::EmissionScheduleUnsorted
+			);
+			EmissionSchedule::<T>::put(schedule.clone());
+			Self::deposit_event(Event::EmissionScheduleSet { schedule });
+			Ok(Pays::No.into())
+		}
+
+		/// Split the minted tokens among `beneficiaries`, the splits must sum up to 100%. The
+		/// accrued blocks are settled first, with the splits their author shares were paid by
+		#[pallet::call_index(5)]
+		#[pallet::weight((Pallet::<T>::settle_weight(), DispatchClass::Normal))]
+		pub fn set_beneficiaries(
+			origin: OriginFor<T>,
+			beneficiaries: BeneficiariesOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			let total = beneficiaries
+				.iter()
+				.fold(0u32, |total, (_, split)| total.saturating_add(split.deconstruct()));
+			ensure!(total == Permill::one().deconstruct(), Error::<T>::InvalidBeneficiarySplits);
+			let weight = Self::settle_accrued();
+			Beneficiaries::<T>::put(beneficiaries.clone());
+			Self::deposit_event(Event::BeneficiariesSet { beneficiaries });
+			Ok((Some(Weight::from_parts(195_000_000, 0).saturating_add(weight)), Pays::No).into())
+		}
+
+		/// Mint the emission of the blocks accrued since the last minting without waiting for
+		/// the `MintPeriod` to pass, anyone can call it
+		#[pallet::call_index(6)]
+		#[pallet::weight((Pallet::<T>::settle_weight(), DispatchClass::Normal))]
+		pub fn settle(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let start_block = Self::start_block().ok_or(Error::<T>::MintNotStarted)?;
+			ensure!(Self::mint_state() == State::Running, Error::<T>::MintStopped);
+			let accrued_blocks = Self::accrued_blocks(
+				frame_system::Pallet::<T>::block_number(),
+				start_block.saturating_add(Self::skipped_blocks()),
+			);
+			ensure!(accrued_blocks > Self::settled_blocks(), Error::<T>::NothingToSettle);
+			let weight = Self::do_settle(accrued_blocks);
+			Ok(Some(Weight::from_parts(195_000_000, 0).saturating_add(weight)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn beneficiary_account() -> T::AccountId {
 			T::BeneficiaryId::get().into_account_truncating()
 		}
+
+		/// The accounts receiving the minted tokens in the current block, with their splits
+		pub fn beneficiary_accounts() -> Vec<(T::AccountId, Permill)> {
+			let author = Self::block_author();
+			Self::split_targets()
+				.into_iter()
+				.map(|(beneficiary, split)| {
+					(Self::beneficiary_account_of(beneficiary, &author), split)
+				})
+				.collect()
+		}
+
+		/// The author of the current block, if it can be found in the pre-runtime digests
+		pub fn block_author() -> Option<T::AccountId> {
+			let digest = frame_system::Pallet::<T>::digest();
+			T::FindAuthor::find_author(digest.logs.iter().filter_map(|d| d.as_pre_runtime()))
+		}
+
+		/// `Beneficiaries`, or `BeneficiaryId` alone if none are set
+		fn split_targets() -> Vec<(Beneficiary<T::AccountId>, Permill)> {
+			let beneficiaries = Self::beneficiaries();
+			if beneficiaries.is_empty() {
+				return vec![(Beneficiary::Pallet(T::BeneficiaryId::get()), Permill::one())]
+			}
+			beneficiaries.into_inner()
+		}
+
+		/// The share of an unknown block author stays with `beneficiary_account`
+		fn beneficiary_account_of(
+			beneficiary: Beneficiary<T::AccountId>,
+			author: &Option<T::AccountId>,
+		) -> T::AccountId {
+			match beneficiary {
+				Beneficiary::Account(account) => account,
+				Beneficiary::Pallet(pallet_id) => pallet_id.into_account_truncating(),
+				Beneficiary::BlockAuthor =>
+					author.clone().unwrap_or_else(Self::beneficiary_account),
+			}
+		}
+
+		/// Number of running blocks up to and including `now`, given the start block postponed
+		/// by the skipped blocks
+		pub(crate) fn accrued_blocks(
+			now: BlockNumberFor<T>,
+			effective_start: BlockNumberFor<T>,
+		) -> BlockNumberFor<T> {
+			now.saturating_add(One::one()).saturating_sub(effective_start)
+		}
+
+		/// The entitlement of the running blocks `from..to`, exactly as if each of them were
+		/// minted on its own, capped at `remaining`. It's summed up in closed form by the curve
+		pub(crate) fn blocks_emission(
+			from: BlockNumberFor<T>,
+			to: BlockNumberFor<T>,
+			remaining: BalanceOf<T>,
+		) -> (BalanceOf<T>, Weight) {
+			if from >= to {
+				return (Zero::zero(), Weight::zero())
+			}
+			// 1 `is_exhausted` and 2 `cumulative_emission` calls
+			let weight = T::EmissionCurve::weight().saturating_mul(3);
+			// the rounding of the curve leaves a remainder behind, which is minted in the final
+			// block. The curve stays exhausted once it is, so checking the last block suffices
+			if T::EmissionCurve::is_exhausted(to - One::one()) {
+				return (remaining, weight)
+			}
+			let minted = T::EmissionCurve::cumulative_emission(to)
+				.saturating_sub(T::EmissionCurve::cumulative_emission(from));
+			(minted.min(remaining), weight)
+		}
+
+		/// The entitlement of the running time `from..to` in the `Timestamp` emission mode,
+		/// capped at `remaining`
+		fn time_emission(from: u64, to: u64, remaining: BalanceOf<T>) -> (BalanceOf<T>, Weight) {
+			if from >= to {
+				return (Zero::zero(), Weight::zero())
+			}
+			let mut weight = T::TimeEmissionCurve::weight();
+			let mut minted = T::TimeEmissionCurve::emission_between(from, to);
+			if minted.is_zero() && T::TimeEmissionCurve::is_exhausted(to) {
+				minted = remaining;
+				weight = weight.saturating_add(T::TimeEmissionCurve::weight());
+			}
+			(minted.min(remaining), weight)
+		}
+
+		/// The `BlockAuthor` split of the `Beneficiaries`, zero if the author isn't one of them
+		fn author_split() -> Permill {
+			Self::split_targets()
+				.into_iter()
+				.find(|(beneficiary, _)| *beneficiary == Beneficiary::BlockAuthor)
+				.map_or(Permill::zero(), |(_, split)| split)
+		}
+
+		/// Mints the `BlockAuthor` share of the emission of the current block to its author and
+		/// adds it to the author's `AuthorEarnings`. The other shares are minted in batch by
+		/// `do_settle`, which counts the author shares into `TotalMinted` as well
+		fn reward_author(accrued_blocks: BlockNumberFor<T>) -> Weight {
+			let author_split = Self::author_split();
+			// 1 read: `beneficiaries`
+			let mut weight = T::DbWeight::get().reads_writes(1, 0);
+			if author_split.is_zero() {
+				return weight
+			}
+
+			// the unsettled emission before the current block is set aside, so that the author
+			// shares never exceed what the next settlement counts
+			let remaining = T::TotalIssuance::get().saturating_sub(Self::total_minted());
+			let emission = match Self::emission_mode() {
+				EmissionMode::Blocks => {
+					let settled_blocks = Self::settled_blocks();
+					let elapsed = accrued_blocks.saturating_sub(One::one()).max(settled_blocks);
+					let (unsettled, unsettled_weight) =
+						Self::blocks_emission(settled_blocks, elapsed, remaining);
+					let (emission, curve_weight) = Self::blocks_emission(
+						elapsed,
+						accrued_blocks,
+						remaining.saturating_sub(unsettled),
+					);
+					weight = weight.saturating_add(unsettled_weight).saturating_add(curve_weight);
+					emission
+				},
+				EmissionMode::Timestamp => {
+					let running_time = Self::running_time();
+					let settled_time = Self::settled_time();
+					let from = Self::author_rewarded_time().max(settled_time).min(running_time);
+					let (unsettled, unsettled_weight) =
+						Self::time_emission(settled_time, from, remaining);
+					let (emission, curve_weight) = Self::time_emission(
+						from,
+						running_time,
+						remaining.saturating_sub(unsettled),
+					);
+					AuthorRewardedTime::<T>::put(running_time);
+					// 3 reads, 1 write: `author_rewarded_time`, `settled_time`,
+					// `effective_start_time`
+					weight = weight
+						.saturating_add(unsettled_weight)
+						.saturating_add(curve_weight)
+						.saturating_add(T::DbWeight::get().reads_writes(3, 1));
+					emission
+				},
+			};
+			// 3 reads: `total_minted`, `emission_mode`, `settled_blocks`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 0));
+
+			let amount = author_split.mul_floor(emission);
+			if amount.is_zero() {
+				return weight
+			}
+			let author = Self::block_author();
+			// deposits below the existential deposit of new accounts are dropped
+			let rewarded = match &author {
+				Some(author) => !T::Currency::deposit_creating(author, amount).peek().is_zero(),
+				None => false,
+			};
+			let to = match author {
+				Some(author) if rewarded => {
+					AuthorEarnings::<T>::mutate(&author, |earnings| {
+						earnings.saturating_accrue(amount)
+					});
+					Self::deposit_event(Event::AuthorRewarded { author: author.clone(), amount });
+					author
+				},
+				// the share of an unknown author, or one it can't receive, stays with
+				// `beneficiary_account`
+				_ => {
+					let to = Self::beneficiary_account();
+					let _ = T::Currency::deposit_creating(&to, amount);
+					to
+				},
+			};
+			// 2 reads: `digest`, the authorities of the consensus
+			// 2 reads, 2 writes: the account of the author, `author_earnings`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 2));
+			if Self::on_token_minted_state() == State::Running {
+				weight = weight.saturating_add(T::OnTokenMinted::token_minted(to, amount));
+			}
+			// 1 read: `on_token_minted_state`
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 0))
+		}
+
+		/// The weight of `settle`, the emission of the accrued blocks is summed up in closed form
+		pub(crate) fn settle_weight() -> Weight {
+			Weight::from_parts(195_000_000, 0)
+				.saturating_add(T::EmissionCurve::weight().saturating_mul(3))
+				.saturating_add(T::TimeEmissionCurve::weight().saturating_mul(2))
+		}
+
+		/// Settles the blocks accrued up to the current block, if there are any
+		fn settle_accrued() -> Weight {
+			let Some(start_block) = Self::start_block() else { return Weight::zero() };
+			let accrued_blocks = Self::accrued_blocks(
+				frame_system::Pallet::<T>::block_number(),
+				start_block.saturating_add(Self::skipped_blocks()),
+			);
+			if accrued_blocks <= Self::settled_blocks() {
+				return Weight::zero()
+			}
+			Self::do_settle(accrued_blocks)
+		}
+
+		/// Mints the entitlement of the blocks accrued since the last settlement, except for the
+		/// `BlockAuthor` share which `reward_author` already minted to the author of each block.
+		/// The author share is counted into `TotalMinted` nonetheless
+		pub(crate) fn do_settle(accrued_blocks: BlockNumberFor<T>) -> Weight {
+			let mut weight = Weight::zero();
+			let total_minted = Self::total_minted();
+			let remaining = T::TotalIssuance::get().saturating_sub(total_minted);
+			// 1 read: `total_minted`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 0));
+
+			// hard stop once the total issuance is reached
+			if remaining.is_zero() {
+				SettledBlocks::<T>::put(accrued_blocks);
+				return weight.saturating_add(T::DbWeight::get().reads_writes(0, 1));
+			}
+
+			let author_split = Self::author_split();
+			let emission = match Self::emission_mode() {
+				EmissionMode::Blocks => {
+					let (emission, curve_weight) =
+						Self::blocks_emission(Self::settled_blocks(), accrued_blocks, remaining);
+					// 1 read: `settled_blocks`
+					weight = weight
+						.saturating_add(curve_weight)
+						.saturating_add(T::DbWeight::get().reads_writes(1, 0));
+					emission
+				},
+				EmissionMode::Timestamp => {
+					let settled_time = Self::settled_time();
+					// the time after the last author reward is left to the next settlement, its
+					// author share is paid to the author of the next block
+					let mut running_time = Self::running_time();
+					if !author_split.is_zero() {
+						running_time = running_time.min(Self::author_rewarded_time());
+					}
+					let running_time = running_time.max(settled_time);
+					let (emission, curve_weight) =
+						Self::time_emission(settled_time, running_time, remaining);
+					SettledTime::<T>::put(running_time);
+					// 3 reads, 1 write: `settled_time`, `effective_start_time`,
+					// `author_rewarded_time`
+					weight = weight
+						.saturating_add(curve_weight)
+						.saturating_add(T::DbWeight::get().reads_writes(3, 1));
+					emission
+				},
+			};
+			// 2 reads: `beneficiaries`, `emission_mode`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 0));
+			SettledBlocks::<T>::put(accrued_blocks);
+			// 1 write: `settled_blocks`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(0, 1));
+			// `reward_author` minted at most this much block by block
+			let author_rewards = author_split.mul_floor(emission);
+			let minted = emission.saturating_sub(author_rewards);
+
+			// theoreticlaly we can deal with the minted tokens directly in the trait impl
+			// pallet, without depositing to an account first.
+			// but the purpose of having the extra logic is to make sure the tokens are
+			// minted to the beneficiary account, regardless of what happens callback. Even
+			// if the callback errors out, it's guaranteed that the tokens are
+			// already minted (and stored on an account), which resonates with the "fair
+			// launch" concept.
+			//
+			// Also imagine there's no callback impl, in this case the tokens will still be
+			// minted and accumulated.
+			let on_token_minted = Self::on_token_minted_state() == State::Running;
+			// the other beneficiaries split the rest in proportion to their splits, it goes to
+			// `beneficiary_account` if there are none
+			let mut beneficiaries: Vec<(T::AccountId, u32)> = Self::split_targets()
+				.into_iter()
+				.filter(|(beneficiary, _)| *beneficiary != Beneficiary::BlockAuthor)
+				.map(|(beneficiary, split)| {
+					(Self::beneficiary_account_of(beneficiary, &None), split.deconstruct())
+				})
+				.collect();
+			if beneficiaries.is_empty() {
+				beneficiaries.push((Self::beneficiary_account(), Permill::one().deconstruct()));
+			}
+			// 2 reads: `on_token_minted_state`, `beneficiaries`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 0));
+
+			let parts = beneficiaries
+				.iter()
+				.fold(0u32, |parts, (_, split)| parts.saturating_add(*split));
+			let last = beneficiaries.len().saturating_sub(1);
+			let mut split_so_far = BalanceOf::<T>::zero();
+			let mut total_minted = total_minted.saturating_add(author_rewards);
+			for (i, (to, split)) in beneficiaries.into_iter().enumerate() {
+				// the last beneficiary gets the rounding dust of the splits
+				let amount = if i == last {
+					minted.saturating_sub(split_so_far)
+				} else {
+					mul_rational(minted, split.into(), parts.into())
+				};
+				split_so_far.saturating_accrue(amount);
+				// deposits below the existential deposit of new accounts are dropped, they
+				// are minted later on with the remainder
+				let amount = T::Currency::deposit_creating(&to, amount).peek();
+				total_minted.saturating_accrue(amount);
+				Self::deposit_event(Event::Minted { to: to.clone(), amount });
+				if on_token_minted {
+					weight = weight.saturating_add(T::OnTokenMinted::token_minted(to, amount));
+				}
+			}
+			TotalMinted::<T>::put(total_minted);
+			// 1 write: `total_minted`
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(0, 1));
+
+			if total_minted == T::TotalIssuance::get() {
+				Self::deposit_event(Event::MintCompleted { total_minted });
+			}
+			weight
+		}
+
+		/// Checks that the minted tokens never exceed the total issuance.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				Self::total_minted() <= T::TotalIssuance::get(),
+				"minted more than the total issuance"
+			);
+			Ok(())
+		}
+
+		/// Tokens that will be minted at block `n`, assuming the minting isn't paused from now
+		/// on. Zero if the minting isn't started or `n` is before the (effective) start block
+		pub fn projected_emission(n: BlockNumberFor<T>) -> BalanceOf<T> {
+			let effective_start = match Self::start_block() {
+				Some(start_block) => start_block.saturating_add(Self::skipped_blocks()),
+				None => return Zero::zero(),
+			};
+			if n < effective_start {
+				return Zero::zero()
+			}
+			match Self::emission_mode() {
+				EmissionMode::Blocks => T::EmissionCurve::emission_at(n - effective_start),
+				EmissionMode::Timestamp => {
+					// assume blocks are produced at the expected block time from now on
+					let block_time = T::ExpectedBlockTime::get();
+					let now = frame_system::Pallet::<T>::block_number();
+					let (from, blocks_ahead) = match Self::effective_start_time() {
+						Some(_) => (Self::running_time(), n.saturating_sub(now)),
+						None => (0, n - effective_start),
+					};
+					let at = from.saturating_add(
+						block_time.saturating_mul(blocks_ahead.saturated_into::<u64>()),
+					);
+					T::TimeEmissionCurve::emission_between(at, at.saturating_add(block_time))
+				},
+			}
+		}
+
+		/// The wall-clock time in milliseconds, it's the timestamp of the previous block during
+		/// `on_initialize`
+		pub(crate) fn now_millis() -> u64 {
+			T::UnixTime::now().as_millis().saturated_into()
+		}
+
+		/// Running time (in milliseconds) of the minting in the `Timestamp` emission mode
+		pub fn running_time() -> u64 {
+			match (Self::effective_start_time(), Self::stopped_at()) {
+				(Some(start), Some(stopped_at)) => stopped_at.saturating_sub(start),
+				(Some(start), None) => Self::now_millis().saturating_sub(start),
+				(None, _) => 0,
+			}
+		}
+	}
+}
+
+/// Piecewise emission from the governance-supplied `EmissionSchedule`: the per-block amount of
+/// the last step that has started, nothing before the first step
+pub struct PiecewiseEmission<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> EmissionCurve<BlockNumberFor<T>, BalanceOf<T>> for PiecewiseEmission<T> {
+	fn emission_at(elapsed: BlockNumberFor<T>) -> BalanceOf<T> {
+		let schedule = EmissionSchedule::<T>::get();
+		match schedule.partition_point(|(from, _)| *from <= elapsed) {
+			0 => Zero::zero(),
+			i => schedule[i - 1].1,
+		}
+	}
+
+	fn cumulative_emission(elapsed: BlockNumberFor<T>) -> BalanceOf<T> {
+		let schedule = EmissionSchedule::<T>::get();
+		let mut minted = BalanceOf::<T>::zero();
+		// each step lasts until the next one starts
+		for (i, (from, amount)) in schedule.iter().enumerate() {
+			if *from >= elapsed {
+				break
+			}
+			let to = schedule.get(i + 1).map_or(elapsed, |(next, _)| (*next).min(elapsed));
+			let blocks: BalanceOf<T> = (to - *from).saturated_into::<u128>().saturated_into();
+			minted = minted.saturating_add(amount.saturating_mul(blocks));
+		}
+		minted
+	}
+
+	fn is_exhausted(elapsed: BlockNumberFor<T>) -> bool {
+		// an empty schedule isn't set up yet rather than exhausted
+		match EmissionSchedule::<T>::get().last() {
+			Some((from, amount)) => elapsed >= *from && amount.is_zero(),
+			None => false,
+		}
+	}
+
+	fn weight() -> Weight {
+		// 1 read: `EmissionSchedule`
+		T::DbWeight::get().reads(1)
 	}
 }
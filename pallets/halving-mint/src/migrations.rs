@@ -0,0 +1,147 @@
+//! Storage migrations of the halving-mint pallet.
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_runtime::traits::{One, SaturatedConversion, Saturating};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	/// Marks the blocks minted one by one before the batched minting as settled, so that
+	/// they aren't minted again.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut weight = Weight::zero();
+			// runs before `on_initialize`, so the blocks up to the previous one are minted
+			if let Some(start_block) = StartBlock::<T>::get() {
+				let previous_block =
+					frame_system::Pallet::<T>::block_number().saturating_sub(One::one());
+				let settled_blocks = Pallet::<T>::accrued_blocks(
+					previous_block,
+					start_block.saturating_add(SkippedBlocks::<T>::get()),
+				);
+				SettledBlocks::<T>::put(settled_blocks);
+				// 1 write: `settled_blocks`
+				weight = T::DbWeight::get().reads_writes(0, 1);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			// 4 reads, 1 write: the storage version, `start_block`, `skipped_blocks`, the block
+			// number
+			weight.saturating_add(T::DbWeight::get().reads_writes(4, 1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"wrong storage version"
+			);
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
+
+/// Initializes `TotalMinted` with the emission minted before it was tracked, so that the minting
+/// still stops at the `TotalIssuance`. The emission is summed up in closed form by
+/// `EmissionCurve::cumulative_emission`, an exhausted curve leaves its remainder to the next
+/// block. Only runs on storage version 0, it must come before `v1::MigrateToV1`.
+pub struct InitializeTotalMinted<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for InitializeTotalMinted<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut weight = Weight::zero();
+		// runs before `on_initialize`, so the blocks up to the previous one are minted
+		if let Some(start_block) = StartBlock::<T>::get() {
+			let previous_block =
+				frame_system::Pallet::<T>::block_number().saturating_sub(One::one());
+			let minted_blocks = Pallet::<T>::accrued_blocks(
+				previous_block,
+				start_block.saturating_add(SkippedBlocks::<T>::get()),
+			);
+			let minted =
+				T::EmissionCurve::cumulative_emission(minted_blocks).min(T::TotalIssuance::get());
+			TotalMinted::<T>::put(minted);
+			// 1 write: `total_minted`
+			weight = T::EmissionCurve::weight().saturating_add(T::DbWeight::get().writes(1));
+		}
+
+		// 4 reads: the storage version, `start_block`, `skipped_blocks`, the block number
+		weight.saturating_add(T::DbWeight::get().reads(4))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		Pallet::<T>::do_try_state()
+	}
+}
+
+/// Switches a chain running the `Blocks` emission mode to the `Timestamp` one. The accrued blocks
+/// are settled first, then the running blocks so far are converted into running time by the
+/// `ExpectedBlockTime`, so the schedule carries on where it is and the blocks already minted
+/// aren't minted again.
+pub struct SwitchToTimestampMode<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SwitchToTimestampMode<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::emission_mode() == EmissionMode::Timestamp {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut weight = Weight::zero();
+		// runs before `on_initialize`, so the blocks up to the previous one are accrued
+		if let Some(start_block) = StartBlock::<T>::get() {
+			let previous_block =
+				frame_system::Pallet::<T>::block_number().saturating_sub(One::one());
+			let accrued_blocks = Pallet::<T>::accrued_blocks(
+				previous_block,
+				start_block.saturating_add(SkippedBlocks::<T>::get()),
+			);
+			if !accrued_blocks.is_zero() {
+				// the author shares of the accrued blocks were paid by the `Blocks` curve
+				if accrued_blocks > SettledBlocks::<T>::get() {
+					weight = Pallet::<T>::do_settle(accrued_blocks);
+				}
+				let block_time = T::ExpectedBlockTime::get();
+				let to_millis = |blocks: BlockNumberFor<T>| {
+					block_time.saturating_mul(blocks.saturated_into::<u64>())
+				};
+				let now = Pallet::<T>::now_millis();
+				EffectiveStartTime::<T>::put(now.saturating_sub(to_millis(accrued_blocks)));
+				SettledTime::<T>::put(to_millis(accrued_blocks));
+				// the author of each accrued block is already rewarded
+				AuthorRewardedTime::<T>::put(to_millis(accrued_blocks));
+				if MintState::<T>::get() == State::Stopped {
+					StoppedAt::<T>::put(now);
+				}
+			}
+		}
+		CurrentEmissionMode::<T>::put(EmissionMode::Timestamp);
+
+		weight.saturating_add(T::DbWeight::get().reads_writes(7, 5))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		frame_support::ensure!(
+			Pallet::<T>::emission_mode() == EmissionMode::Timestamp,
+			"emission mode not switched"
+		);
+		Ok(())
+	}
+}
@@ -1,12 +1,12 @@
-use crate::{self as pallet_halving_mint, OnTokenMinted};
+use crate::{self as pallet_halving_mint, Halving, OnTokenMinted, TimeHalving};
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU64, Currency, ExistenceRequirement, Hooks},
+	traits::{ConstU64, Currency, ExistenceRequirement, FindAuthor, Hooks, UnixTime},
 	weights::Weight,
 	PalletId,
 };
 use sp_core::ConstU32;
-use sp_runtime::BuildStorage;
+use sp_runtime::{BuildStorage, ConsensusEngineId};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -49,6 +49,29 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const BeneficiaryId: PalletId = PalletId(*b"can/hlvm");
+	pub static BlockAuthor: Option<u64> = None;
+	pub static MintPeriod: u64 = 1;
+	pub static Now: u64 = 0;
+}
+
+pub const BLOCK_TIME: u64 = 6_000;
+
+/// The timestamp in milliseconds set by `run_to_block`, like the timestamp inherent would
+pub struct MockUnixTime;
+impl UnixTime for MockUnixTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(Now::get())
+	}
+}
+
+pub struct MockFindAuthor;
+impl FindAuthor<u64> for MockFindAuthor {
+	fn find_author<'a, I>(_digests: I) -> Option<u64>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		BlockAuthor::get()
+	}
 }
 
 impl pallet_halving_mint::Config for Test {
@@ -56,8 +79,15 @@ impl pallet_halving_mint::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = frame_system::EnsureRoot<u64>;
 	type TotalIssuance = ConstU64<1000>;
-	type HalvingInterval = ConstU32<10>;
+	type EmissionCurve = Halving<ConstU64<1000>, ConstU32<10>>;
+	type MaxEmissionSteps = ConstU32<4>;
 	type BeneficiaryId = BeneficiaryId;
+	type MaxBeneficiaries = ConstU32<4>;
+	type FindAuthor = MockFindAuthor;
+	type MintPeriod = MintPeriod;
+	type UnixTime = MockUnixTime;
+	type TimeEmissionCurve = TimeHalving<ConstU64<1000>, ConstU64<60_000>>;
+	type ExpectedBlockTime = ConstU64<BLOCK_TIME>;
 	type OnTokenMinted = TransferOnTokenMinted<Test>;
 }
 
@@ -79,6 +109,7 @@ pub fn run_to_block(n: u64) {
 		System::set_block_number(System::block_number() + 1);
 		System::on_initialize(System::block_number());
 		HalvingMint::on_initialize(System::block_number());
+		Now::mutate(|now| *now += BLOCK_TIME);
 	}
 }
 
@@ -0,0 +1,19 @@
+//! Runtime API definition for the halving-mint pallet.
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait HalvingMintApi<AccountId, BlockNumber, Balance>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// Tokens minted at block `n` according to the emission curve, assuming the minting
+		/// isn't paused from now on
+		fn projected_emission(n: BlockNumber) -> Balance;
+
+		/// The `BlockAuthor` shares `author` earned so far
+		fn author_earnings(author: AccountId) -> Balance;
+	}
+}
@@ -1,5 +1,10 @@
-use crate::{mock::*, Error, Event, State};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, Beneficiary, CurrentEmissionMode, EmissionCurve, EmissionMode, Error, Event, Halving,
+	LinearDecay, PiecewiseEmission, State, TimeEmissionCurve, TimeHalving, TotalMinted,
+};
+use frame_support::{assert_noop, assert_ok, traits::ConstU64};
+use sp_core::ConstU32;
+use sp_runtime::Permill;
 
 #[test]
 fn set_mint_state_check_works() {
@@ -82,20 +87,26 @@ fn halving_mint_works() {
 		assert_eq!(Balances::free_balance(&beneficiary), 10);
 		assert_eq!(Balances::free_balance(&1), 961);
 
-		run_to_block(62);
-		// the sixth halving - but 0 tokens will be minted
+		run_to_block(61);
 		assert_eq!(Balances::total_issuance(), 980);
+		assert_eq!(HalvingMint::total_minted(), 970);
+
+		run_to_block(62);
+		// the sixth halving - the curve is exhausted, the remaining 30 tokens that got lost in
+		// the integer division are minted at once
+		assert_eq!(Balances::total_issuance(), 1010);
 		assert_eq!(Balances::free_balance(&beneficiary), 10);
-		assert_eq!(Balances::free_balance(&1), 970);
+		assert_eq!(Balances::free_balance(&1), 1000);
+		assert_eq!(HalvingMint::total_minted(), 1000);
+		System::assert_has_event(Event::MintCompleted { total_minted: 1000 }.into());
 
 		run_to_block(1_000);
-		// no changes since the sixth halving, the total minted token will be fixated on 980,
-		// the "missing" 20 comes from the integer division and the total_issuance is too small.
-		//
-		// we'll have much accurate result in reality where token unit is 18 decimal
-		assert_eq!(Balances::total_issuance(), 980);
+		// no changes since the sixth halving, the total minted token is fixated on 1000
+		assert_eq!(Balances::total_issuance(), 1010);
 		assert_eq!(Balances::free_balance(&beneficiary), 10);
-		assert_eq!(Balances::free_balance(&1), 970);
+		assert_eq!(Balances::free_balance(&1), 1000);
+		assert_eq!(HalvingMint::total_minted(), 1000);
+		assert_ok!(HalvingMint::do_try_state());
 	});
 }
 
@@ -167,3 +178,574 @@ fn set_mint_state_works() {
 		assert_eq!(Balances::free_balance(&1), 525);
 	});
 }
+
+#[test]
+fn halving_curve_is_capped() {
+	type Curve = Halving<ConstU64<{ u64::MAX }>, ConstU32<1>>;
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(0), u64::MAX / 2);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(62), 1);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(63), 0);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(64), 0);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(u64::MAX), 0);
+}
+
+#[test]
+fn linear_decay_curve_works() {
+	type Curve = LinearDecay<ConstU64<1000>, ConstU32<10>>;
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(0), 181);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(5), 90);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(9), 18);
+	assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(10), 0);
+	let total: u64 = (0..20).map(<Curve as EmissionCurve<u64, u64>>::emission_at).sum();
+	assert!(total <= 1000);
+}
+
+#[test]
+fn piecewise_emission_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HalvingMint::set_emission_schedule(
+				RuntimeOrigin::signed(1),
+				vec![(0, 10)].try_into().unwrap()
+			),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			HalvingMint::set_emission_schedule(
+				RuntimeOrigin::root(),
+				vec![(5, 10), (5, 20)].try_into().unwrap()
+			),
+			Error::<Test>::EmissionScheduleUnsorted,
+		);
+
+		let schedule = vec![(2, 30), (5, 10), (8, 0)].try_into().unwrap();
+		assert_ok!(HalvingMint::set_emission_schedule(RuntimeOrigin::root(), schedule));
+		System::assert_last_event(
+			Event::EmissionScheduleSet {
+				schedule: vec![(2, 30), (5, 10), (8, 0)].try_into().unwrap(),
+			}
+			.into(),
+		);
+
+		type Curve = PiecewiseEmission<Test>;
+		assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(0), 0);
+		assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(2), 30);
+		assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(4), 30);
+		assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(5), 10);
+		assert_eq!(<Curve as EmissionCurve<u64, u64>>::emission_at(100), 0);
+	});
+}
+
+#[test]
+fn projected_emission_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(HalvingMint::projected_emission(5), 0);
+		assert_ok!(HalvingMint::start_mint_from_block(RuntimeOrigin::root(), 5));
+		assert_eq!(HalvingMint::projected_emission(4), 0);
+		assert_eq!(HalvingMint::projected_emission(5), 50);
+		assert_eq!(HalvingMint::projected_emission(15), 25);
+		assert_eq!(HalvingMint::projected_emission(1_000), 0);
+
+		// skipped blocks postpone the schedule
+		run_to_block(6);
+		assert_ok!(HalvingMint::set_mint_state(RuntimeOrigin::root(), State::Stopped));
+		run_to_block(8);
+		assert_eq!(HalvingMint::skipped_blocks(), 2);
+		assert_eq!(HalvingMint::projected_emission(15), 50);
+		assert_eq!(HalvingMint::projected_emission(17), 25);
+	});
+}
+
+#[test]
+fn minting_stops_at_total_issuance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		TotalMinted::<Test>::put(990);
+
+		run_to_block(2);
+		// only the exact remainder is minted
+		assert_eq!(Balances::total_issuance(), 20);
+		assert_eq!(Balances::free_balance(&1), 10);
+		assert_eq!(HalvingMint::total_minted(), 1000);
+		System::assert_has_event(
+			Event::Minted { to: HalvingMint::beneficiary_account(), amount: 10 }.into(),
+		);
+		System::assert_last_event(Event::MintCompleted { total_minted: 1000 }.into());
+
+		run_to_block(5);
+		assert_eq!(Balances::total_issuance(), 20);
+		assert_eq!(HalvingMint::total_minted(), 1000);
+		assert_ok!(HalvingMint::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_overminting() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HalvingMint::do_try_state());
+		TotalMinted::<Test>::put(1001);
+		assert!(HalvingMint::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn set_beneficiaries_checks_splits() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HalvingMint::set_beneficiaries(
+				RuntimeOrigin::signed(1),
+				vec![(Beneficiary::Account(2), Permill::one())].try_into().unwrap()
+			),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			HalvingMint::set_beneficiaries(RuntimeOrigin::root(), Default::default()),
+			Error::<Test>::InvalidBeneficiarySplits,
+		);
+		assert_noop!(
+			HalvingMint::set_beneficiaries(
+				RuntimeOrigin::root(),
+				vec![
+					(Beneficiary::Account(2), Permill::from_percent(60)),
+					(Beneficiary::Account(3), Permill::from_percent(30)),
+				]
+				.try_into()
+				.unwrap()
+			),
+			Error::<Test>::InvalidBeneficiarySplits,
+		);
+
+		let beneficiaries = vec![
+			(Beneficiary::Account(2), Permill::from_percent(60)),
+			(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(40)),
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(HalvingMint::set_beneficiaries(RuntimeOrigin::root(), beneficiaries));
+		assert_eq!(
+			HalvingMint::beneficiary_accounts(),
+			vec![
+				(2, Permill::from_percent(60)),
+				(HalvingMint::beneficiary_account(), Permill::from_percent(40))
+			]
+		);
+	});
+}
+
+#[test]
+fn minted_tokens_are_split_among_beneficiaries() {
+	new_test_ext().execute_with(|| {
+		let beneficiary = HalvingMint::beneficiary_account();
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![
+				(Beneficiary::Account(2), Permill::from_percent(33)),
+				(Beneficiary::Account(3), Permill::from_percent(33)),
+				(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(34)),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		assert_ok!(HalvingMint::set_on_token_minted_state(RuntimeOrigin::root(), State::Stopped));
+
+		run_to_block(2);
+		assert_eq!(Balances::free_balance(&2), 16);
+		assert_eq!(Balances::free_balance(&3), 16);
+		// the last beneficiary gets the rounding dust
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 18);
+		System::assert_has_event(Event::Minted { to: 2, amount: 16 }.into());
+		System::assert_has_event(Event::Minted { to: 3, amount: 16 }.into());
+		System::assert_has_event(Event::Minted { to: beneficiary, amount: 18 }.into());
+		assert_eq!(HalvingMint::total_minted(), 50);
+
+		// `OnTokenMinted` is called for each of them
+		assert_ok!(HalvingMint::set_on_token_minted_state(RuntimeOrigin::root(), State::Running));
+		run_to_block(3);
+		assert_eq!(Balances::free_balance(&1), 50);
+		assert_eq!(HalvingMint::total_minted(), 100);
+	});
+}
+
+#[test]
+fn deposits_below_existential_deposit_are_minted_later() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![
+				(Beneficiary::Account(2), Permill::from_percent(2)),
+				(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(98)),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+
+		run_to_block(2);
+		// 1 token is below the existential deposit of 2
+		assert_eq!(Balances::free_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&1), 49);
+		assert_eq!(HalvingMint::total_minted(), 49);
+		assert_ok!(HalvingMint::do_try_state());
+	});
+}
+
+#[test]
+fn block_author_is_rewarded() {
+	new_test_ext().execute_with(|| {
+		let beneficiary = HalvingMint::beneficiary_account();
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![
+				(Beneficiary::BlockAuthor, Permill::from_percent(20)),
+				(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(80)),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		assert_ok!(HalvingMint::set_on_token_minted_state(RuntimeOrigin::root(), State::Stopped));
+
+		BlockAuthor::set(Some(7));
+		run_to_block(2);
+		assert_eq!(Balances::free_balance(&7), 10);
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 40);
+		System::assert_has_event(Event::AuthorRewarded { author: 7, amount: 10 }.into());
+		assert_eq!(HalvingMint::author_earnings(7), 10);
+
+		BlockAuthor::set(Some(8));
+		run_to_block(4);
+		assert_eq!(Balances::free_balance(&8), 20);
+		assert_eq!(HalvingMint::author_earnings(7), 10);
+		assert_eq!(HalvingMint::author_earnings(8), 20);
+
+		// the share of an unknown author stays with the beneficiary account
+		BlockAuthor::set(None);
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 40 * 3 + 50);
+		assert_eq!(HalvingMint::author_earnings(7), 10);
+		assert_eq!(HalvingMint::author_earnings(8), 20);
+		assert_eq!(HalvingMint::total_minted(), 200);
+	});
+}
+
+#[test]
+fn author_shares_below_existential_deposit_stay_with_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let beneficiary = HalvingMint::beneficiary_account();
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![
+				(Beneficiary::BlockAuthor, Permill::from_percent(2)),
+				(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(98)),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		assert_ok!(HalvingMint::set_on_token_minted_state(RuntimeOrigin::root(), State::Stopped));
+
+		BlockAuthor::set(Some(7));
+		run_to_block(2);
+		// 1 token is below the existential deposit of 2
+		assert_eq!(Balances::free_balance(&7), 0);
+		assert_eq!(HalvingMint::author_earnings(7), 0);
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 50);
+		assert_eq!(HalvingMint::total_minted(), 50);
+	});
+}
+
+#[test]
+fn minting_is_batched_every_mint_period() {
+	new_test_ext().execute_with(|| {
+		MintPeriod::set(5);
+		assert_noop!(HalvingMint::settle(RuntimeOrigin::signed(2)), Error::<Test>::MintNotStarted);
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		assert_noop!(HalvingMint::settle(RuntimeOrigin::signed(2)), Error::<Test>::NothingToSettle);
+
+		run_to_block(5);
+		assert_eq!(Balances::total_issuance(), 10);
+		assert_eq!(HalvingMint::settled_blocks(), 0);
+
+		run_to_block(6);
+		// the emission of blocks 2..=6 is minted at once
+		assert_eq!(Balances::total_issuance(), 260);
+		assert_eq!(Balances::free_balance(&1), 250);
+		assert_eq!(HalvingMint::settled_blocks(), 5);
+		System::assert_has_event(
+			Event::Minted { to: HalvingMint::beneficiary_account(), amount: 250 }.into(),
+		);
+
+		// anyone can settle in between
+		run_to_block(8);
+		assert_ok!(HalvingMint::settle(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::total_issuance(), 360);
+		assert_eq!(HalvingMint::settled_blocks(), 7);
+		assert_noop!(HalvingMint::settle(RuntimeOrigin::signed(2)), Error::<Test>::NothingToSettle);
+
+		// skipped blocks don't accrue
+		run_to_block(9);
+		assert_ok!(HalvingMint::set_mint_state(RuntimeOrigin::root(), State::Stopped));
+		assert_noop!(HalvingMint::settle(RuntimeOrigin::signed(2)), Error::<Test>::MintStopped);
+		run_to_block(11);
+		assert_ok!(HalvingMint::set_mint_state(RuntimeOrigin::root(), State::Running));
+		assert_eq!(HalvingMint::skipped_blocks(), 2);
+
+		run_to_block(14);
+		assert_eq!(Balances::total_issuance(), 360);
+		run_to_block(15);
+		// 10 blocks before the first halving and 2 after it, same as minting every block
+		assert_eq!(Balances::total_issuance(), 10 + 500 + 2 * 25);
+		assert_eq!(HalvingMint::settled_blocks(), 12);
+		assert_ok!(HalvingMint::do_try_state());
+	});
+}
+
+#[test]
+fn block_authors_are_rewarded_every_block_when_batched() {
+	new_test_ext().execute_with(|| {
+		MintPeriod::set(5);
+		let beneficiary = HalvingMint::beneficiary_account();
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![
+				(Beneficiary::BlockAuthor, Permill::from_percent(20)),
+				(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(80)),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		assert_ok!(HalvingMint::set_on_token_minted_state(RuntimeOrigin::root(), State::Stopped));
+
+		BlockAuthor::set(Some(7));
+		run_to_block(3);
+		// the authors are rewarded right away, the rest waits for the settlement
+		assert_eq!(Balances::free_balance(&7), 20);
+		assert_eq!(Balances::free_balance(&beneficiary), 10);
+		System::assert_last_event(Event::AuthorRewarded { author: 7, amount: 10 }.into());
+		// which counts the author rewards as well
+		assert_eq!(HalvingMint::total_minted(), 0);
+		assert_eq!(HalvingMint::settled_blocks(), 0);
+
+		BlockAuthor::set(Some(8));
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(&8), 30);
+		assert_eq!(Balances::free_balance(&7), 20);
+		// 80% of the emission of blocks 2..=6
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 200);
+		assert_eq!(HalvingMint::settled_blocks(), 5);
+		assert_eq!(HalvingMint::total_minted(), 250);
+		assert_eq!(HalvingMint::author_earnings(7), 20);
+		assert_eq!(HalvingMint::author_earnings(8), 30);
+		assert_ok!(HalvingMint::do_try_state());
+	});
+}
+
+#[test]
+fn set_beneficiaries_settles_the_accrued_blocks() {
+	new_test_ext().execute_with(|| {
+		MintPeriod::set(5);
+		let beneficiary = HalvingMint::beneficiary_account();
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![
+				(Beneficiary::BlockAuthor, Permill::from_percent(20)),
+				(Beneficiary::Pallet(BeneficiaryId::get()), Permill::from_percent(80)),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		assert_ok!(HalvingMint::set_on_token_minted_state(RuntimeOrigin::root(), State::Stopped));
+
+		BlockAuthor::set(Some(7));
+		run_to_block(3);
+		assert_eq!(HalvingMint::total_minted(), 0);
+
+		// the author shares of blocks 2 and 3 were paid with the 20% split
+		assert_ok!(HalvingMint::set_beneficiaries(
+			RuntimeOrigin::root(),
+			vec![(Beneficiary::Pallet(BeneficiaryId::get()), Permill::one())]
+				.try_into()
+				.unwrap()
+		));
+		assert_eq!(HalvingMint::settled_blocks(), 2);
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 80);
+		assert_eq!(HalvingMint::total_minted(), 100);
+
+		run_to_block(8);
+		assert_eq!(Balances::free_balance(&7), 20);
+		assert_eq!(Balances::free_balance(&beneficiary), 10 + 80 + 250);
+		assert_eq!(HalvingMint::total_minted(), 350);
+		assert_ok!(HalvingMint::do_try_state());
+	});
+}
+
+#[test]
+fn settle_mints_all_accrued_blocks_at_once() {
+	new_test_ext().execute_with(|| {
+		MintPeriod::set(10);
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		run_to_block(9);
+		assert_eq!(HalvingMint::settled_blocks(), 0);
+
+		// the emission is summed up in closed form, the weight doesn't grow with the blocks
+		let info = HalvingMint::settle(RuntimeOrigin::signed(2)).unwrap();
+		assert!(info.actual_weight.unwrap().all_lte(HalvingMint::settle_weight()));
+		assert_eq!(HalvingMint::settled_blocks(), 8);
+		assert_eq!(Balances::total_issuance(), 10 + 8 * 50);
+		assert_noop!(HalvingMint::settle(RuntimeOrigin::signed(2)), Error::<Test>::NothingToSettle);
+	});
+}
+
+type Migrations =
+	(crate::migrations::InitializeTotalMinted<Test>, crate::migrations::v1::MigrateToV1<Test>);
+
+#[test]
+fn migrate_to_v1_settles_minted_blocks() {
+	use frame_support::traits::{Hooks, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<HalvingMint>();
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		crate::SkippedBlocks::<Test>::put(3);
+		System::set_block_number(20);
+
+		Migrations::on_runtime_upgrade();
+		// blocks 5..=19 were minted before the upgrade, 10 before the first halving and 5
+		// after it
+		assert_eq!(HalvingMint::settled_blocks(), 15);
+		assert_eq!(HalvingMint::total_minted(), 10 * 50 + 5 * 25);
+		assert_eq!(StorageVersion::get::<HalvingMint>(), 1);
+
+		HalvingMint::on_initialize(20);
+		assert_eq!(HalvingMint::settled_blocks(), 16);
+		assert_eq!(HalvingMint::total_minted(), 10 * 50 + 6 * 25);
+	});
+}
+
+#[test]
+fn initialize_total_minted_leaves_the_remainder_to_the_next_block() {
+	use frame_support::traits::{Hooks, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<HalvingMint>();
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		System::set_block_number(2_000);
+
+		Migrations::on_runtime_upgrade();
+		// the curve was exhausted long before the upgrade, without minting the rounding
+		// remainder
+		assert_eq!(HalvingMint::total_minted(), 970);
+		assert_ok!(HalvingMint::do_try_state());
+
+		let issuance = Balances::total_issuance();
+		HalvingMint::on_initialize(2_000);
+		assert_eq!(Balances::total_issuance(), issuance + 30);
+		assert_eq!(HalvingMint::total_minted(), 1000);
+
+		// migrated chains are left alone
+		TotalMinted::<Test>::put(0);
+		crate::migrations::InitializeTotalMinted::<Test>::on_runtime_upgrade();
+		assert_eq!(HalvingMint::total_minted(), 0);
+	});
+}
+
+#[test]
+fn cumulative_emission_sums_up_the_blocks() {
+	fn assert_sums<C: EmissionCurve<u64, u64>>(blocks: u64) {
+		for elapsed in 0..blocks {
+			let sum: u64 = (0..elapsed).map(C::emission_at).sum();
+			assert_eq!(C::cumulative_emission(elapsed), sum);
+		}
+	}
+
+	assert_sums::<Halving<ConstU64<1000>, ConstU32<10>>>(100);
+	assert_sums::<Halving<ConstU64<{ u64::MAX }>, ConstU32<1>>>(70);
+	assert_sums::<LinearDecay<ConstU64<1000>, ConstU32<10>>>(20);
+	assert_sums::<LinearDecay<ConstU64<123_456_789>, ConstU32<997>>>(1_100);
+	new_test_ext().execute_with(|| {
+		assert_sums::<PiecewiseEmission<Test>>(10);
+		let schedule = vec![(2, 30), (5, 10), (8, 0)].try_into().unwrap();
+		assert_ok!(HalvingMint::set_emission_schedule(RuntimeOrigin::root(), schedule));
+		assert_sums::<PiecewiseEmission<Test>>(20);
+	});
+}
+
+#[test]
+fn time_halving_curve_works() {
+	type Curve = TimeHalving<ConstU64<1000>, ConstU64<100>>;
+	assert_eq!(<Curve as TimeEmissionCurve<u64>>::emission_between(0, 100), 500);
+	assert_eq!(<Curve as TimeEmissionCurve<u64>>::emission_between(0, 10), 50);
+	// across the first halving
+	assert_eq!(<Curve as TimeEmissionCurve<u64>>::emission_between(90, 110), 50 + 25);
+	assert_eq!(<Curve as TimeEmissionCurve<u64>>::emission_between(0, 200), 750);
+	assert!(!<Curve as TimeEmissionCurve<u64>>::is_exhausted(800));
+	assert!(<Curve as TimeEmissionCurve<u64>>::is_exhausted(900));
+	assert_eq!(<Curve as TimeEmissionCurve<u64>>::emission_between(6_300, u64::MAX), 0);
+}
+
+#[test]
+fn timestamp_mode_mints_by_elapsed_time() {
+	new_test_ext().execute_with(|| {
+		CurrentEmissionMode::<Test>::put(EmissionMode::Timestamp);
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+
+		run_to_block(2);
+		assert_eq!(HalvingMint::effective_start_time(), Some(0));
+		assert_eq!(Balances::total_issuance(), 10);
+
+		run_to_block(3);
+		// the 6 seconds of block 2
+		assert_eq!(Balances::total_issuance(), 60);
+		assert_eq!(HalvingMint::projected_emission(3), 50);
+		assert_eq!(HalvingMint::projected_emission(12), 25);
+
+		// a missed slot, twice as much time elapsed since the last block
+		Now::mutate(|now| *now += BLOCK_TIME);
+		run_to_block(4);
+		assert_eq!(Balances::total_issuance(), 160);
+
+		run_to_block(11);
+		// the first 60 seconds before the halving
+		assert_eq!(Balances::total_issuance(), 510);
+		run_to_block(12);
+		assert_eq!(Balances::total_issuance(), 535);
+
+		// the stopped time doesn't count
+		assert_ok!(HalvingMint::set_mint_state(RuntimeOrigin::root(), State::Stopped));
+		run_to_block(20);
+		assert_eq!(Balances::total_issuance(), 535);
+		assert_ok!(HalvingMint::set_mint_state(RuntimeOrigin::root(), State::Running));
+		assert_eq!(HalvingMint::effective_start_time(), Some(48_000));
+		run_to_block(21);
+		assert_eq!(Balances::total_issuance(), 560);
+		assert_ok!(HalvingMint::do_try_state());
+	});
+}
+
+#[test]
+fn switch_to_timestamp_mode_carries_on_the_schedule() {
+	use frame_support::traits::{Hooks, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(HalvingMint::start_mint_from_next_block(RuntimeOrigin::root()));
+		run_to_block(6);
+		assert_eq!(Balances::total_issuance(), 260);
+
+		// upgrade at block 7
+		System::set_block_number(7);
+		crate::migrations::SwitchToTimestampMode::<Test>::on_runtime_upgrade();
+		assert_eq!(HalvingMint::emission_mode(), EmissionMode::Timestamp);
+		// 5 blocks of 6 seconds were minted
+		assert_eq!(HalvingMint::effective_start_time(), Some(0));
+		assert_eq!(HalvingMint::settled_time(), 30_000);
+		HalvingMint::on_initialize(7);
+		Now::mutate(|now| *now += BLOCK_TIME);
+		assert_eq!(Balances::total_issuance(), 260);
+
+		run_to_block(8);
+		assert_eq!(Balances::total_issuance(), 310);
+	});
+}
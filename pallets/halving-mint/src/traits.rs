@@ -1,5 +1,11 @@
 /// Traits for pallet-halving-mint
-use frame_support::pallet_prelude::Weight;
+use frame_support::{pallet_prelude::Weight, traits::Get};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	Rounding,
+};
+use sp_std::marker::PhantomData;
 
 pub trait OnTokenMinted<AccountId, Balance> {
 	fn token_minted(beneficiary: AccountId, amount: Balance) -> Weight;
@@ -10,3 +16,231 @@ impl<AccountId, Balance> OnTokenMinted<AccountId, Balance> for () {
 		Weight::zero()
 	}
 }
+
+/// The emission schedule: how many tokens are minted per block
+pub trait EmissionCurve<BlockNumber, Balance> {
+	/// Tokens to mint in the block that is `elapsed` blocks after the (effective) start block,
+	/// the first minted block has `elapsed` = 0
+	fn emission_at(elapsed: BlockNumber) -> Balance;
+
+	/// Tokens minted in the first `elapsed` blocks, the sum of `emission_at` over `0..elapsed`
+	/// computed without going through the blocks one by one
+	fn cumulative_emission(elapsed: BlockNumber) -> Balance;
+
+	/// If nothing will be minted from `elapsed` on, the remainder of the total issuance is then
+	/// minted at once
+	fn is_exhausted(elapsed: BlockNumber) -> bool;
+
+	/// The weight of one `emission_at`, `cumulative_emission` or `is_exhausted` call
+	fn weight() -> Weight;
+}
+
+/// BTC-like halving: `TotalIssuance / (2 * HalvingInterval)` tokens per block, halved after
+/// each `HalvingInterval` blocks.
+///
+/// The halving is a bit shift, once the round reaches the bit width of `Balance` nothing is
+/// minted anymore (similar to bitcoin's `if (halvings >= 64) return 0;`)
+pub struct Halving<TotalIssuance, HalvingInterval>(PhantomData<(TotalIssuance, HalvingInterval)>);
+
+impl<BlockNumber, Balance, TotalIssuance, HalvingInterval> EmissionCurve<BlockNumber, Balance>
+	for Halving<TotalIssuance, HalvingInterval>
+where
+	BlockNumber: AtLeast32BitUnsigned,
+	Balance: AtLeast32BitUnsigned,
+	TotalIssuance: Get<Balance>,
+	HalvingInterval: Get<u32>,
+{
+	fn emission_at(elapsed: BlockNumber) -> Balance {
+		let halving_interval = HalvingInterval::get();
+		let round: u32 = (elapsed / halving_interval.into()).unique_saturated_into();
+		if round >= (sp_std::mem::size_of::<Balance>() * 8) as u32 {
+			return Zero::zero()
+		}
+		(TotalIssuance::get() / halving_interval.saturating_mul(2).into()) >> round
+	}
+
+	fn cumulative_emission(elapsed: BlockNumber) -> Balance {
+		let halving_interval = HalvingInterval::get();
+		let initial = || TotalIssuance::get() / halving_interval.saturating_mul(2).into();
+		let max_round = (sp_std::mem::size_of::<Balance>() * 8) as u32;
+		let elapsed: u128 = elapsed.unique_saturated_into();
+		let rounds: u32 = (elapsed / u128::from(halving_interval)).unique_saturated_into();
+		// the geometric sum of the completed rounds, bounded by the bit width of `Balance`
+		let mut minted = Balance::zero();
+		for round in 0..rounds.min(max_round) {
+			minted =
+				minted.saturating_add((initial() >> round).saturating_mul(halving_interval.into()));
+		}
+		if rounds < max_round {
+			let blocks = (elapsed % u128::from(halving_interval)) as u32;
+			minted = minted.saturating_add((initial() >> rounds).saturating_mul(blocks.into()));
+		}
+		minted
+	}
+
+	fn is_exhausted(elapsed: BlockNumber) -> bool {
+		// the curve never increases
+		Self::emission_at(elapsed).is_zero()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Linearly decaying emission: starts at `2 * TotalIssuance / (DecayPeriod + 1)` tokens per block
+/// and drops to zero after `DecayPeriod` blocks, so that the sum never exceeds `TotalIssuance`
+pub struct LinearDecay<TotalIssuance, DecayPeriod>(PhantomData<(TotalIssuance, DecayPeriod)>);
+
+impl<BlockNumber, Balance, TotalIssuance, DecayPeriod> EmissionCurve<BlockNumber, Balance>
+	for LinearDecay<TotalIssuance, DecayPeriod>
+where
+	BlockNumber: AtLeast32BitUnsigned,
+	Balance: AtLeast32BitUnsigned,
+	TotalIssuance: Get<Balance>,
+	DecayPeriod: Get<u32>,
+{
+	fn emission_at(elapsed: BlockNumber) -> Balance {
+		let decay_period = DecayPeriod::get();
+		let elapsed: u32 = elapsed.unique_saturated_into();
+		if elapsed >= decay_period {
+			return Zero::zero()
+		}
+		let initial = TotalIssuance::get().saturating_mul(2u32.into()) /
+			decay_period.saturating_add(1).into();
+		mul_rational(initial, (decay_period - elapsed).into(), decay_period.into())
+	}
+
+	fn cumulative_emission(elapsed: BlockNumber) -> Balance {
+		let decay_period = DecayPeriod::get();
+		let blocks: u32 = elapsed.unique_saturated_into();
+		let blocks = u128::from(blocks.min(decay_period));
+		if blocks.is_zero() {
+			return Zero::zero()
+		}
+		let initial: u128 = (TotalIssuance::get().saturating_mul(2u32.into()) /
+			decay_period.saturating_add(1).into())
+		.unique_saturated_into();
+		// each block mints `initial * k / decay_period` rounded down, for `k` from
+		// `decay_period - blocks + 1` to `decay_period`
+		let decay_period = u128::from(decay_period);
+		let first = decay_period - blocks + 1;
+		let (quotient, remainder) = (initial / decay_period, initial % decay_period);
+		let steps = blocks * (first + decay_period) / 2;
+		Balance::unique_saturated_from(quotient.saturating_mul(steps).saturating_add(floor_sum(
+			blocks,
+			decay_period,
+			remainder,
+			remainder * first,
+		)))
+	}
+
+	fn is_exhausted(elapsed: BlockNumber) -> bool {
+		// the curve never increases
+		Self::emission_at(elapsed).is_zero()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The emission schedule over the running time of the minting, in milliseconds
+pub trait TimeEmissionCurve<Balance> {
+	/// Tokens to mint for the running time `from..to`
+	fn emission_between(from: u64, to: u64) -> Balance;
+
+	/// If nothing will be minted from `at` on, the remainder of the total issuance is then
+	/// minted at once
+	fn is_exhausted(at: u64) -> bool;
+
+	/// The weight of one `emission_between` or `is_exhausted` call
+	fn weight() -> Weight;
+}
+
+/// Halving over wall-clock time: `TotalIssuance / 2` tokens are minted evenly over the first
+/// `HalvingPeriod` milliseconds, half of it over the next `HalvingPeriod` and so on.
+///
+/// Like `Halving` nothing is minted anymore once the round reaches the bit width of `Balance`
+pub struct TimeHalving<TotalIssuance, HalvingPeriod>(PhantomData<(TotalIssuance, HalvingPeriod)>);
+
+impl<Balance, TotalIssuance, HalvingPeriod> TimeEmissionCurve<Balance>
+	for TimeHalving<TotalIssuance, HalvingPeriod>
+where
+	Balance: AtLeast32BitUnsigned,
+	TotalIssuance: Get<Balance>,
+	HalvingPeriod: Get<u64>,
+{
+	fn emission_between(from: u64, to: u64) -> Balance {
+		let halving_period = HalvingPeriod::get().max(1);
+		let max_round = (sp_std::mem::size_of::<Balance>() * 8) as u64;
+		let mut amount = Balance::zero();
+		let mut at = from;
+		// the range normally lies within one round, it only spans more after a long stall
+		while at < to {
+			let round = at / halving_period;
+			if round >= max_round {
+				break
+			}
+			let round_end = to.min(round.saturating_add(1).saturating_mul(halving_period));
+			let minted = mul_rational(
+				TotalIssuance::get(),
+				round_end - at,
+				halving_period.saturating_mul(2),
+			);
+			amount = amount.saturating_add(minted >> round as u32);
+			at = round_end;
+		}
+		amount
+	}
+
+	fn is_exhausted(at: u64) -> bool {
+		let round = at / HalvingPeriod::get().max(1);
+		// nothing is left for the whole round
+		round.saturating_add(1) >= (sp_std::mem::size_of::<Balance>() * 8) as u64 ||
+			(TotalIssuance::get() >> (round as u32).saturating_add(1)).is_zero()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The sum of `(a * i + b) / m` rounded down for `i` in `0..n`, in `O(log m)` steps.
+/// `m` must be non-zero
+fn floor_sum(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+	let mut sum = 0u128;
+	loop {
+		if a >= m {
+			sum = sum.saturating_add((n * n.saturating_sub(1) / 2).saturating_mul(a / m));
+			a %= m;
+		}
+		if b >= m {
+			sum = sum.saturating_add(n.saturating_mul(b / m));
+			b %= m;
+		}
+		let y_max = a * n + b;
+		if y_max < m {
+			return sum
+		}
+		n = y_max / m;
+		b = y_max % m;
+		sp_std::mem::swap(&mut m, &mut a);
+	}
+}
+
+/// `amount * numerator / denominator` rounded down, without overflowing in between
+pub(crate) fn mul_rational<Balance: AtLeast32BitUnsigned>(
+	amount: Balance,
+	numerator: u64,
+	denominator: u64,
+) -> Balance {
+	multiply_by_rational_with_rounding(
+		amount.unique_saturated_into(),
+		numerator.into(),
+		denominator.into(),
+		Rounding::Down,
+	)
+	.map(Balance::unique_saturated_from)
+	.unwrap_or_else(Zero::zero)
+}
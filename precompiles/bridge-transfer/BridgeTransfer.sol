@@ -10,4 +10,25 @@ interface IBridgeTransfer {
     /// @custom:selector 0x6e700f9a
 	/// 				 transferAssets(uint256,uint8,bytes32,bytes)
     function transferAssets(uint256 amount, uint8 dest_id, bytes32 resource_id, bytes calldata recipient) external;
+
+	/// @notice Used to transfer assets through token bridge, along with calldata executed by
+	///         the destination bridge handler.
+	/// @param amount: The amount of tokens to be transferred.
+    /// @param dest_id: The destination chain id indicator
+    /// @param resource_id: Resource indicator of type of assets transferred
+    /// @param recipient: Recipient address, typically H160/H256
+    /// @param payload: Calldata executed on the destination chain
+    /// @custom:selector 0xf3eeef28
+	/// 				 transferAssetsWithCall(uint256,uint8,bytes32,bytes,bytes)
+    function transferAssetsWithCall(uint256 amount, uint8 dest_id, bytes32 resource_id, bytes calldata recipient, bytes calldata payload) external;
+
+	/// @notice Used to transfer assets to several recipients through token bridge, all or nothing.
+	/// Each transfer pays the bridge fee and becomes its own deposit, like `transferAssets`.
+	/// @param amounts: The amount of tokens to be transferred to each recipient.
+    /// @param recipients: Recipient addresses, typically H160/H256
+    /// @param dest_id: The destination chain id indicator
+    /// @param resource_id: Resource indicator of type of assets transferred
+    /// @custom:selector 0xe2dadc2b
+	/// 				 transferAssetsBatch(uint256[],bytes[],uint8,bytes32)
+    function transferAssetsBatch(uint256[] calldata amounts, bytes[] calldata recipients, uint8 dest_id, bytes32 resource_id) external;
 }
@@ -5,23 +5,29 @@ use fp_evm::{PrecompileFailure, PrecompileHandle};
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::AddressMapping;
 use precompile_utils::prelude::*;
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::{traits::Dispatchable, BoundedVec};
 
 use sp_core::{H256, U256};
 use sp_std::{marker::PhantomData, vec::Vec};
 
 use pallet_bridge_transfer::BalanceOf;
 
-pub struct BridgeTransferPrecompile<Runtime>(PhantomData<Runtime>);
+type RecipientOf<Runtime, Instance> =
+	BoundedVec<u8, <Runtime as pallet_bridge_transfer::Config<Instance>>::MaxRecipientLength>;
+
+pub struct BridgeTransferPrecompile<Runtime, Instance: 'static = ()>(
+	PhantomData<(Runtime, Instance)>,
+);
 
 #[precompile_utils::precompile]
-impl<Runtime> BridgeTransferPrecompile<Runtime>
+impl<Runtime, Instance> BridgeTransferPrecompile<Runtime, Instance>
 where
-	Runtime: pallet_bridge_transfer::Config + pallet_evm::Config,
+	Instance: 'static,
+	Runtime: pallet_bridge_transfer::Config<Instance> + pallet_evm::Config,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
-	Runtime::RuntimeCall: From<pallet_bridge_transfer::Call<Runtime>>,
+	Runtime::RuntimeCall: From<pallet_bridge_transfer::Call<Runtime, Instance>>,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
-	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+	BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256>,
 {
 	#[precompile::public("transferAssets(uint256,uint8,bytes32,bytes)")]
 	fn transfer_assets(
@@ -33,15 +39,54 @@ where
 	) -> EvmResult {
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 
-		let amount: BalanceOf<Runtime> = amount.try_into().map_err(|_| {
+		let amount: BalanceOf<Runtime, Instance> = amount.try_into().map_err(|_| {
+			Into::<PrecompileFailure>::into(RevertReason::value_is_too_large("balance type"))
+		})?;
+		let recipient: Vec<u8> = recipient.into();
+		let recipient = recipient.try_into().map_err(|_| {
+			Into::<PrecompileFailure>::into(RevertReason::value_is_too_large("recipient"))
+		})?;
+		let resource_id = resource_id.into();
+
+		let call = pallet_bridge_transfer::Call::<Runtime, Instance>::transfer_assets {
+			amount,
+			recipient,
+			dest_id,
+			resource_id,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("transferAssetsWithCall(uint256,uint8,bytes32,bytes,bytes)")]
+	fn transfer_assets_with_call(
+		handle: &mut impl PrecompileHandle,
+		amount: U256,
+		dest_id: u8,
+		resource_id: H256,
+		recipient: UnboundedBytes,
+		payload: UnboundedBytes,
+	) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		let amount: BalanceOf<Runtime, Instance> = amount.try_into().map_err(|_| {
 			Into::<PrecompileFailure>::into(RevertReason::value_is_too_large("balance type"))
 		})?;
 		let recipient: Vec<u8> = recipient.into();
+		let recipient = recipient.try_into().map_err(|_| {
+			Into::<PrecompileFailure>::into(RevertReason::value_is_too_large("recipient"))
+		})?;
+		let payload: Vec<u8> = payload.into();
+		let payload = payload.try_into().map_err(|_| {
+			Into::<PrecompileFailure>::into(RevertReason::value_is_too_large("payload"))
+		})?;
 		let resource_id = resource_id.into();
 
-		let call = pallet_bridge_transfer::Call::<Runtime>::transfer_assets {
+		let call = pallet_bridge_transfer::Call::<Runtime, Instance>::transfer_assets_with_call {
 			amount,
 			recipient,
+			payload,
 			dest_id,
 			resource_id,
 		};
@@ -49,4 +94,75 @@ where
 
 		Ok(())
 	}
+
+	#[precompile::public("transferAssetsBatch(uint256[],bytes[],uint8,bytes32)")]
+	fn transfer_assets_batch(
+		handle: &mut impl PrecompileHandle,
+		amounts: Vec<U256>,
+		recipients: Vec<UnboundedBytes>,
+		dest_id: u8,
+		resource_id: H256,
+	) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		if amounts.len() != recipients.len() {
+			return Err(RevertReason::custom("amounts and recipients length mismatch").into());
+		}
+		let transfers = amounts
+			.into_iter()
+			.zip(recipients)
+			.map(|(amount, recipient)| {
+				let amount: BalanceOf<Runtime, Instance> = amount.try_into().map_err(|_| {
+					Into::<PrecompileFailure>::into(RevertReason::value_is_too_large(
+						"balance type",
+					))
+				})?;
+				let recipient: Vec<u8> = recipient.into();
+				let recipient: RecipientOf<Runtime, Instance> =
+					recipient.try_into().map_err(|_| {
+						Into::<PrecompileFailure>::into(RevertReason::value_is_too_large(
+							"recipient",
+						))
+					})?;
+				Ok((amount, recipient))
+			})
+			.collect::<EvmResult<Vec<(BalanceOf<Runtime, Instance>, RecipientOf<Runtime, Instance>)>>>(
+			)?;
+		let transfers = transfers.try_into().map_err(|_| {
+			Into::<PrecompileFailure>::into(RevertReason::value_is_too_large("transfers"))
+		})?;
+		let resource_id = resource_id.into();
+
+		let call = pallet_bridge_transfer::Call::<Runtime, Instance>::transfer_assets_batch {
+			transfers,
+			dest_id,
+			resource_id,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Emits a log from this precompile. Only callable by the precompile address itself, which
+	/// the runtime uses as the source of the transactions carrying the logs of bridge-ins to
+	/// EVM recipients, so they end up in a receipt.
+	#[precompile::public("depositLog(bytes32[],bytes)")]
+	fn deposit_log(
+		handle: &mut impl PrecompileHandle,
+		topics: Vec<H256>,
+		data: UnboundedBytes,
+	) -> EvmResult {
+		let address = handle.code_address();
+		if handle.context().caller != address {
+			return Err(RevertReason::custom("only callable by the precompile itself").into());
+		}
+		if topics.len() > 4 {
+			return Err(RevertReason::custom("too many topics").into());
+		}
+		let data: Vec<u8> = data.into();
+		handle.record_log_costs_manual(topics.len(), data.len())?;
+		handle.log(address, topics, data)?;
+
+		Ok(())
+	}
 }
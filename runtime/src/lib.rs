@@ -31,12 +31,11 @@ use sp_std::{marker::PhantomData, prelude::*};
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
-use frame_support::genesis_builder_helper::{build_config, create_default_config};
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, FindAuthor,
-		KeyOwnerProofSystem, OnFinalize, Randomness, SortedMembers, StorageInfo,
+		KeyOwnerProofSystem, OnFinalize, Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -46,6 +45,10 @@ pub use frame_support::{
 	},
 	ConsensusEngineId, PalletId, StorageValue,
 };
+use frame_support::{
+	genesis_builder_helper::{build_config, create_default_config},
+	instances::Instance1,
+};
 pub use frame_system::Call as SystemCall;
 use frame_system::{EnsureRoot, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
@@ -317,6 +320,19 @@ impl<F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorTruncated<F> {
 	}
 }
 
+/// Resolves the Aura author index into its AccountId
+pub struct FindAuthorAccountId<F>(PhantomData<F>);
+impl<F: FindAuthor<u32>> FindAuthor<AccountId> for FindAuthorAccountId<F> {
+	fn find_author<'a, I>(digests: I) -> Option<AccountId>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		let author_index = F::find_author(digests)?;
+		let authority_id = Aura::authorities().get(author_index as usize)?.clone();
+		AccountId::decode(&mut authority_id.as_slice()).ok()
+	}
+}
+
 const BLOCK_GAS_LIMIT: u64 = 75_000_000;
 const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 
@@ -400,15 +416,27 @@ impl pallet_template::Config for Runtime {
 
 parameter_types! {
 	pub const HavlingMintId: PalletId = PalletId(*b"can/hlvm");
+	pub const HalvingMintTotalIssuance: Balance = 21_000_000 * UNIT;
 }
 
 impl pallet_halving_mint::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type ManagerOrigin = frame_system::EnsureRoot<Self::AccountId>;
-	type TotalIssuance = ConstU128<{ 21_000_000 * UNIT }>;
-	type HalvingInterval = ConstU32<{ 5 * YEARS }>;
+	type TotalIssuance = HalvingMintTotalIssuance;
+	type EmissionCurve =
+		pallet_halving_mint::Halving<HalvingMintTotalIssuance, ConstU32<{ 5 * YEARS }>>;
+	type MaxEmissionSteps = ConstU32<64>;
 	type BeneficiaryId = HavlingMintId;
+	type MaxBeneficiaries = ConstU32<16>;
+	type FindAuthor = FindAuthorAccountId<Aura>;
+	type MintPeriod = ConstU32<{ HOURS }>;
+	type UnixTime = Timestamp;
+	type TimeEmissionCurve = pallet_halving_mint::TimeHalving<
+		HalvingMintTotalIssuance,
+		ConstU64<{ 5 * YEARS as u64 * MILLISECS_PER_BLOCK }>,
+	>;
+	type ExpectedBlockTime = ConstU64<MILLISECS_PER_BLOCK>;
 	type OnTokenMinted = ();
 }
 
@@ -483,8 +511,11 @@ impl pallet_assets::Config for Runtime {
 parameter_types! {
 	pub const BridgeChainId: u8 = 2; // TODO: Determine our chain id
 	pub const ProposalLifetime: BlockNumber = 50400; // ~7 days
+	pub const MaxRelayers: u32 = 32;
 	pub const TreasuryPalletId: PalletId = PalletId(*b"can/bdge");
 	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+	pub const ChainBridgePalletId: PalletId = PalletId(*b"litry/bg");
+	pub const LightClientBridgePalletId: PalletId = PalletId(*b"can/lcbg");
 }
 
 impl pallet_bridge::Config for Runtime {
@@ -492,38 +523,214 @@ impl pallet_bridge::Config for Runtime {
 	type BridgeCommitteeOrigin = EnsureRoot<AccountId>;
 	type Proposal = RuntimeCall;
 	type BridgeChainId = BridgeChainId;
+	type BridgePalletId = ChainBridgePalletId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
-	type WeightInfo = ();
+	type MaxRelayers = MaxRelayers;
+	type WeightInfo = pallet_bridge::weights::LitentryWeight<Runtime>;
+}
+
+impl pallet_bridge::Config<Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BridgeCommitteeOrigin = EnsureRoot<AccountId>;
+	type Proposal = RuntimeCall;
+	type BridgeChainId = BridgeChainId;
+	type BridgePalletId = LightClientBridgePalletId;
+	type Balance = Balance;
+	type ProposalLifetime = ProposalLifetime;
+	type MaxRelayers = MaxRelayers;
+	type WeightInfo = pallet_bridge::weights::LitentryWeight<Runtime>;
 }
 
-// allow anyone to call transfer_native
-pub struct TransferNativeAnyone;
-impl SortedMembers<AccountId> for TransferNativeAnyone {
-	fn sorted_members() -> Vec<AccountId> {
-		vec![]
+parameter_types! {
+	pub const BridgeLimitPeriod: BlockNumber = DAYS;
+}
+
+// gates both bridges, open to anyone until the allowlist is enabled
+impl pallet_bridge_limits::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type LimitPeriod = BridgeLimitPeriod;
+	type WeightInfo = pallet_bridge_limits::weights::LitentryWeight<Runtime>;
+}
+
+parameter_types! {
+	// logs of bridge-ins to EVM recipients come from the bridge transfer precompiles
+	pub ChainBridgeEvmLogAddress: H160 = H160::from_low_u64_be(20482);
+	pub LightClientBridgeEvmLogAddress: H160 = H160::from_low_u64_be(20483);
+}
+
+// gas of the transactions carrying bridge-in logs, covers the intrinsic cost, the calldata and
+// a log with four topics and a few words of data
+pub const BRIDGE_LOG_GAS_LIMIT: u64 = 60_000;
+
+// Frontier serves `eth_getLogs` and receipts from `pallet_ethereum` transactions only, so a
+// bridge-in log is emitted by a zero fee transaction from the log address to itself, calling the
+// `depositLog` selector of the bridge transfer precompile at that address. Like the XCM
+// transactions of other Frontier chains it isn't signed nor validated, just applied. Should that
+// fail, the log is deposited as a `pallet_evm` log event instead.
+pub struct DepositEvmLog;
+impl pallet_bridge_transfer::EvmLogHandler for DepositEvmLog {
+	fn deposit_log(log: pallet_evm::Log) {
+		use fp_ethereum::ValidatedTransaction as _;
+		use pallet_bridge_transfer_precompile::BridgeTransferPrecompileCall;
+
+		let source = log.address;
+		let input: Vec<u8> = BridgeTransferPrecompileCall::<Runtime, ()>::deposit_log {
+			topics: log.topics.clone(),
+			data: log.data.clone().into(),
+		}
+		.into();
+		let transaction = EthereumTransaction::Legacy(ethereum::LegacyTransaction {
+			nonce: EVM::account_basic(&source).0.nonce,
+			gas_price: U256::zero(),
+			gas_limit: U256::from(BRIDGE_LOG_GAS_LIMIT),
+			action: TransactionAction::Call(source),
+			value: U256::zero(),
+			input,
+			// never recovered, only makes the transaction encodable
+			signature: ethereum::TransactionSignature::new(
+				27,
+				H256::from_low_u64_be(1),
+				H256::from_low_u64_be(1),
+			)
+			.expect("27 is a valid v, r and s are in range; qed"),
+		});
+		if pallet_ethereum::ValidatedTransaction::<Runtime>::apply(source, transaction).is_err() {
+			System::deposit_event(pallet_evm::Event::<Runtime>::Log { log });
+		}
 	}
 
-	fn contains(_who: &AccountId) -> bool {
-		true
+	fn deposit_log_weight() -> Weight {
+		use pallet_evm::GasWeightMapping;
+		<Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(BRIDGE_LOG_GAS_LIMIT, true)
+			// nonce of the source, pending transactions and the `Executed` event
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 2))
 	}
+}
 
-	#[cfg(feature = "runtime-benchmarks")]
-	fn add(_: &AccountId) {
-		unimplemented!()
+// calls the targets of bridge-ins to contracts
+pub struct BridgeEvmCaller;
+impl pallet_bridge_transfer::EvmCaller for BridgeEvmCaller {
+	fn call(
+		source: H160,
+		target: H160,
+		input: Vec<u8>,
+		gas_limit: u64,
+	) -> pallet_bridge_transfer::EvmCallOutcome {
+		let result = <Runtime as pallet_evm::Config>::Runner::call(
+			source,
+			target,
+			input,
+			U256::zero(),
+			gas_limit,
+			None,
+			None,
+			None,
+			Vec::new(),
+			false,
+			false,
+			None,
+			None,
+			<Runtime as pallet_evm::Config>::config(),
+		);
+		match result {
+			Ok(info) => pallet_bridge_transfer::EvmCallOutcome {
+				succeeded: matches!(info.exit_reason, fp_evm::ExitReason::Succeed(_)),
+				used_gas: info.used_gas.effective.unique_saturated_into(),
+			},
+			Err(_) =>
+				pallet_bridge_transfer::EvmCallOutcome { succeeded: false, used_gas: gas_limit },
+		}
+	}
+
+	fn gas_to_weight(gas: u64) -> Weight {
+		use pallet_evm::GasWeightMapping;
+		<Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas, true)
+	}
+}
+
+// ERC-20 precompile address of bridged assets
+pub struct AssetPrecompileAddress;
+impl sp_runtime::traits::Convert<AssetId, H160> for AssetPrecompileAddress {
+	fn convert(asset_id: AssetId) -> H160 {
+		Runtime::asset_id_to_address(asset_id)
 	}
 }
 
+parameter_types! {
+	pub const BridgeReleaseDelay: BlockNumber = DAYS;
+	pub const ChainBridgeCustodyPalletId: PalletId = PalletId(*b"can/bdcu");
+	pub const LightClientBridgeCustodyPalletId: PalletId = PalletId(*b"can/lccu");
+	pub BridgeFeeStakingPot: AccountId = StakingPoolId::get().into_account_truncating();
+}
+
 impl pallet_assets_handler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type TreasuryAccount = TreasuryAccount;
+	type StakingRewardPot = BridgeFeeStakingPot;
+	type MaxFeeRecipients = ConstU32<16>;
+	type AssetAddress = AssetPrecompileAddress;
+	type GuardianOrigin = EnsureRoot<AccountId>;
+	type ReleaseDelay = BridgeReleaseDelay;
+	type CustodyPalletId = ChainBridgeCustodyPalletId;
+	type LocalDecimals = ConstU8<18>;
+	type BridgeOnlyAssets = pallet_assets_handler::RegisteredAssets<Runtime>;
+	type WeightInfo = pallet_assets_handler::weights::LitentryWeight<Runtime>;
 }
 
 impl pallet_bridge_transfer::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgeOrigin = pallet_bridge::EnsureBridge<Runtime>;
-	type TransferNativeMembers = TransferNativeAnyone;
-	type BridgeHandler = AssetsHandler;
-	type WeightInfo = ();
+	type TransferNativeMembers = BridgeLimits;
+	type BridgeHandler =
+		pallet_bridge_limits::LimitedBridgeHandler<Runtime, AssetsHandler, ChainBridgePalletId>;
+	type AddressMapping = <Runtime as pallet_evm::Config>::AddressMapping;
+	type EvmLogAddress = ChainBridgeEvmLogAddress;
+	type EvmLogHandler = DepositEvmLog;
+	type MaxRecipientLength = ConstU32<128>;
+	type MaxBatchTransfers = ConstU32<64>;
+	type MaxPayloadSize = ConstU32<8192>;
+	type EvmCaller = BridgeEvmCaller;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_assets_handler::NativeBenchmarkHelper<Runtime>;
+	type WeightInfo = pallet_bridge_transfer::weights::LitentryWeight<Runtime>;
+}
+
+impl pallet_assets_handler::Config<Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type TreasuryAccount = TreasuryAccount;
+	type StakingRewardPot = BridgeFeeStakingPot;
+	type MaxFeeRecipients = ConstU32<16>;
+	type AssetAddress = AssetPrecompileAddress;
+	type GuardianOrigin = EnsureRoot<AccountId>;
+	type ReleaseDelay = BridgeReleaseDelay;
+	type CustodyPalletId = LightClientBridgeCustodyPalletId;
+	type LocalDecimals = ConstU8<18>;
+	type BridgeOnlyAssets = pallet_assets_handler::RegisteredAssets<Runtime, Instance1>;
+	type WeightInfo = pallet_assets_handler::weights::LitentryWeight<Runtime>;
+}
+
+impl pallet_bridge_transfer::Config<Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BridgeOrigin = pallet_bridge::EnsureBridge<Runtime, Instance1>;
+	type TransferNativeMembers = BridgeLimits;
+	type BridgeHandler = pallet_bridge_limits::LimitedBridgeHandler<
+		Runtime,
+		LightClientAssetsHandler,
+		LightClientBridgePalletId,
+	>;
+	type AddressMapping = <Runtime as pallet_evm::Config>::AddressMapping;
+	type EvmLogAddress = LightClientBridgeEvmLogAddress;
+	type EvmLogHandler = DepositEvmLog;
+	type MaxRecipientLength = ConstU32<128>;
+	type MaxBatchTransfers = ConstU32<64>;
+	type MaxPayloadSize = ConstU32<8192>;
+	type EvmCaller = BridgeEvmCaller;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_assets_handler::NativeBenchmarkHelper<Runtime, Instance1>;
+	type WeightInfo = pallet_bridge_transfer::weights::LitentryWeight<Runtime>;
 }
 
 parameter_types! {
@@ -563,6 +770,10 @@ construct_runtime!(
 		Template: pallet_template = 15,
 		HalvingMint: pallet_halving_mint = 16,
 		StableStaking: pallet_stable_staking = 17,
+		LightClientBridge: pallet_bridge::<Instance1> = 18,
+		LightClientAssetsHandler: pallet_assets_handler::<Instance1> = 19,
+		LightClientBridgeTransfer: pallet_bridge_transfer::<Instance1> = 20,
+		BridgeLimits: pallet_bridge_limits = 21,
 	}
 );
 
@@ -618,6 +829,14 @@ pub type CheckedExtrinsic =
 
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_assets_handler::migrations::v1::MigrateToV1<Runtime>,
+	pallet_assets_handler::migrations::v1::MigrateToV1<Runtime, Instance1>,
+	pallet_halving_mint::migrations::InitializeTotalMinted<Runtime>,
+	pallet_halving_mint::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -625,6 +844,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -695,6 +915,10 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
+		[pallet_bridge, ChainBridge]
+		[pallet_assets_handler, AssetsHandler]
+		[pallet_bridge_transfer, BridgeTransfer]
+		[pallet_bridge_limits, BridgeLimits]
 	);
 }
 
@@ -1061,6 +1285,114 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_bridge::runtime_api::BridgeApi<Block> for Runtime {
+		fn relayer_set_id(bridge_id: PalletId) -> Option<pallet_bridge::RelayerSetId> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() => Some(ChainBridge::relayer_set_id()),
+				id if id == LightClientBridgePalletId::get() => Some(LightClientBridge::relayer_set_id()),
+				_ => None,
+			}
+		}
+
+		fn relayer_set(
+			bridge_id: PalletId,
+			set_id: pallet_bridge::RelayerSetId,
+		) -> Option<pallet_bridge::RelayerSet> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() => ChainBridge::relayer_sets(set_id),
+				id if id == LightClientBridgePalletId::get() => LightClientBridge::relayer_sets(set_id),
+				_ => None,
+			}
+		}
+
+		fn relayer_set_change_proof(
+			bridge_id: PalletId,
+			set_id: pallet_bridge::RelayerSetId,
+		) -> Option<pallet_bridge::RelayerSetChangeProof> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() => ChainBridge::relayer_set_change_proof(set_id),
+				id if id == LightClientBridgePalletId::get() =>
+					LightClientBridge::relayer_set_change_proof(set_id),
+				_ => None,
+			}
+		}
+
+		fn withdrawal_signatures(
+			bridge_id: PalletId,
+			dest_id: pallet_bridge::BridgeChainId,
+			nonce: pallet_bridge::DepositNonce,
+		) -> Option<Vec<u8>> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() =>
+					ChainBridge::packed_withdrawal_signatures(dest_id, nonce),
+				id if id == LightClientBridgePalletId::get() =>
+					LightClientBridge::packed_withdrawal_signatures(dest_id, nonce),
+				_ => None,
+			}
+		}
+	}
+
+	impl pallet_assets_handler::runtime_api::AssetsHandlerApi<Block, AssetId, AccountId, Balance, BlockNumber> for Runtime {
+		fn pending_releases(bridge_id: PalletId) -> Option<Vec<(
+			pallet_assets_handler::ReleaseId,
+			pallet_assets_handler::PendingRelease<AssetId, AccountId, Balance, BlockNumber>,
+		)>> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() => Some(AssetsHandler::pending_releases()),
+				id if id == LightClientBridgePalletId::get() =>
+					Some(LightClientAssetsHandler::pending_releases()),
+				_ => None,
+			}
+		}
+
+		fn quote_bridge_out(
+			bridge_id: PalletId,
+			resource_id: pallet_bridge::ResourceId,
+			amount: Balance,
+		) -> Option<pallet_assets_handler::FeeQuote<Balance>> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() =>
+					AssetsHandler::quote_bridge_out(resource_id, amount),
+				id if id == LightClientBridgePalletId::get() =>
+					LightClientAssetsHandler::quote_bridge_out(resource_id, amount),
+				_ => None,
+			}
+		}
+
+		fn quote_bridge_in(
+			bridge_id: PalletId,
+			resource_id: pallet_bridge::ResourceId,
+			amount: Balance,
+		) -> Option<Balance> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() =>
+					AssetsHandler::quote_bridge_in(resource_id, amount),
+				id if id == LightClientBridgePalletId::get() =>
+					LightClientAssetsHandler::quote_bridge_in(resource_id, amount),
+				_ => None,
+			}
+		}
+
+		fn outstanding_supplies(bridge_id: PalletId) -> Option<Vec<(pallet_bridge::ResourceId, Balance)>> {
+			match bridge_id {
+				id if id == ChainBridgePalletId::get() => Some(AssetsHandler::outstanding_supplies()),
+				id if id == LightClientBridgePalletId::get() =>
+					Some(LightClientAssetsHandler::outstanding_supplies()),
+				_ => None,
+			}
+		}
+	}
+
+	impl pallet_halving_mint::runtime_api::HalvingMintApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn projected_emission(n: BlockNumber) -> Balance {
+			HalvingMint::projected_emission(n)
+		}
+
+		fn author_earnings(author: AccountId) -> Balance {
+			HalvingMint::author_earnings(author)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -3,6 +3,7 @@ use core::marker::PhantomData;
 use fp_evm::{ExitError, PrecompileFailure};
 use frame_support::{
 	dispatch::{DispatchClass, GetDispatchInfo, Pays},
+	instances::Instance1,
 	parameter_types,
 	traits::Contains,
 };
@@ -104,6 +105,11 @@ pub type PrecompilesSetAt<R> = (
 		BridgeTransferPrecompile<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
+	PrecompileAt<
+		AddressU64<20483>,
+		BridgeTransferPrecompile<R, Instance1>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 );
 
 pub type Precompiles<R> = PrecompileSetBuilder<
@@ -112,7 +118,7 @@ pub type Precompiles<R> = PrecompileSetBuilder<
 		// Skip precompiles if out of range.
 		PrecompilesInRangeInclusive<
 			// We take range as last precompile index, UPDATE this once new prcompile is added
-			(AddressU64<1>, AddressU64<20482>),
+			(AddressU64<1>, AddressU64<20483>),
 			PrecompilesSetAt<R>,
 		>,
 		// Prefixed precompile sets (XC20)
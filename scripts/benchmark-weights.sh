@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+# Regenerates the weights of the CAN pallets with the benchmark CLI. Run it on reference
+# hardware from the repository root, optionally restricted to some pallets:
+#
+#   ./scripts/benchmark-weights.sh [pallet_bridge pallet_assets_handler ...]
+set -e
+
+PALLETS=(
+	"pallet_bridge:pallets/bridge"
+	"pallet_assets_handler:pallets/assets-handler"
+	"pallet_bridge_transfer:pallets/bridge-transfer"
+	"pallet_bridge_limits:pallets/bridge-limits"
+)
+
+echo "*** Building the node with runtime benchmarks"
+cargo build --release --features runtime-benchmarks
+
+for entry in "${PALLETS[@]}"; do
+	pallet="${entry%%:*}"
+	path="${entry#*:}"
+	if [ $# -gt 0 ] && [[ ! " $* " =~ " $pallet " ]]; then
+		continue
+	fi
+
+	echo "*** Benchmarking $pallet"
+	./target/release/canbus benchmark pallet \
+		--chain=dev \
+		--wasm-execution=compiled \
+		--pallet="$pallet" \
+		--extrinsic='*' \
+		--heap-pages=4096 \
+		--steps=50 \
+		--repeat=20 \
+		--header=./.maintain/header.txt \
+		--template=./.maintain/frame-weight-template.hbs \
+		--output="./$path/src/weights.rs"
+done